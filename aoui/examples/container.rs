@@ -42,7 +42,7 @@ pub fn init(mut commands: AouiCommands) {
         dimension: Dimension::INHERIT,
         ..Default::default()
     }, Container {
-        layout: Box::new(SpanLayout {
+        layout: LayoutObject::new(SpanLayout {
             direction: LayoutDir::LeftToRight,
             stretch: false,
         }),
@@ -181,7 +181,7 @@ pub fn egui_window(mut commands: Commands, mut ctx: EguiContexts,
                         ui.selectable_value(direction, LayoutDir::TopToBottom, "top to bottom");
                     });
                 } else {
-                    container.layout = Box::new(StackLayout { 
+                    container.layout = LayoutObject::new(StackLayout { 
                         direction: LayoutDir::LeftToRight
                     })
                 }
@@ -203,7 +203,7 @@ pub fn egui_window(mut commands: Commands, mut ctx: EguiContexts,
                     });
                     ui.checkbox(stretch, "Stretch");
                 } else {
-                    container.layout = Box::new(SpanLayout { 
+                    container.layout = LayoutObject::new(SpanLayout { 
                         direction: LayoutDir::LeftToRight, 
                         stretch: false 
                     })
@@ -258,7 +258,7 @@ pub fn egui_window(mut commands: Commands, mut ctx: EguiContexts,
                     }
                     ui.checkbox(stretch, "Stretch");
                 } else {
-                    container.layout = Box::new(ParagraphLayout { 
+                    container.layout = LayoutObject::new(ParagraphLayout { 
                         direction: LayoutDir::LeftToRight, 
                         stack: LayoutDir::TopToBottom, 
                         stretch: false
@@ -348,7 +348,7 @@ pub fn egui_window(mut commands: Commands, mut ctx: EguiContexts,
                     
                     ui.checkbox(stretch, "Stretch");
                 } else {
-                    container.layout = Box::new(SizedGridLayout { 
+                    container.layout = LayoutObject::new(SizedGridLayout { 
                         cell_size: Vec2::splat(40.0).into(),
                         row_dir: LayoutDir::LeftToRight, 
                         column_dir: LayoutDir::TopToBottom, 
@@ -438,7 +438,7 @@ pub fn egui_window(mut commands: Commands, mut ctx: EguiContexts,
                         }
                     }
                     } else {
-                    container.layout = Box::new(FixedGridLayout  { 
+                    container.layout = LayoutObject::new(FixedGridLayout  { 
                         cells: UVec2 { x: 5, y: 5 }, 
                         row_dir: LayoutDir::LeftToRight, 
                         column_dir: LayoutDir::TopToBottom, 
@@ -498,7 +498,7 @@ pub fn egui_window(mut commands: Commands, mut ctx: EguiContexts,
                     }
                     ui.checkbox(stretch, "Stretch");
                 } else {
-                    container.layout = Box::new(DynamicTableLayout{ 
+                    container.layout = LayoutObject::new(DynamicTableLayout{ 
                         columns: 5, 
                         row_dir: LayoutDir::LeftToRight, 
                         column_dir: LayoutDir::TopToBottom, 
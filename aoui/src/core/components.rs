@@ -1,8 +1,11 @@
+use std::sync::Arc;
+
 use bevy::{prelude::*, reflect::Reflect, math::Affine2};
 
 /// Stores opacity of the widget, not used by default but
 /// can be used by implementors.
 #[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct Opacity {
     /// User specified opacity of the widget.
     pub opacity: f32,
@@ -69,24 +72,83 @@ impl Default for Opacity {
 }
 
 /// Ignores writing opacity to the associated alpha value of sprite, text, etc.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect, Default)]
+#[reflect(Component, Default)]
 pub struct IgnoreAlpha;
 
+/// An intersection of clipping regions.
+///
+/// Each region is stored as an affine that maps the clipping rect
+/// to the unit square centered at the origin.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClipRegion(Option<Arc<[Affine2]>>);
+
+impl ClipRegion {
+    /// A region that clips nothing.
+    pub const UNBOUNDED: Self = Self(None);
+
+    /// Returns true if nothing is clipped.
+    pub fn is_unbounded(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Iterate through inverse affines of all clipping rects.
+    pub fn iter(&self) -> impl Iterator<Item = &Affine2> {
+        self.0.iter().flat_map(|x| x.iter())
+    }
+
+    /// Intersect with a rect, given the inverse of its affine.
+    pub fn intersect(&self, inverse: Affine2) -> Self {
+        Self(Some(self.iter().copied().chain(std::iter::once(inverse)).collect()))
+    }
+
+    /// Intersection of the axis aligned bounding boxes of all clipping rects,
+    /// `None` if unbounded.
+    ///
+    /// This is exact if none of the clipping rects are rotated or skewed.
+    pub fn bounding_rect(&self) -> Option<Rect> {
+        self.iter().map(|inverse| {
+            let affine = inverse.inverse();
+            [Vec2::new(-0.5, -0.5), Vec2::new(0.5, -0.5), Vec2::new(-0.5, 0.5), Vec2::new(0.5, 0.5)]
+                .into_iter()
+                .map(|v| affine.transform_point2(v))
+                .fold(Rect::new(f32::MAX, f32::MAX, f32::MIN, f32::MIN), |rect, v| Rect {
+                    min: rect.min.min(v),
+                    max: rect.max.max(v),
+                })
+        }).reduce(|a, b| a.intersect(b))
+    }
+
+    /// Check if a point is inside all clipping rects.
+    pub fn contains(&self, pos: Vec2) -> bool {
+        self.iter().all(|affine| {
+            let vec = affine.transform_point2(pos);
+            vec.x.abs() <= 0.5 && vec.y.abs() <= 0.5
+        })
+    }
+}
+
 /// Data related to clipping.
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct Clipping {
     /// If set, use this sprite's bounding rectangle to clip its children.
     /// 
-    /// This currently only affect events, you need `clipping_layer` for
-    /// render clipping. This might change in the future.
+    /// This occludes events and clips sprites, texts and
+    /// [`ClippingMaterial`](crate::widgets::clipping::ClippingMaterial)s per pixel
+    /// to the region's bounding rectangle.
+    /// Rotated sprites and texts are only culled if entirely outside of the region,
+    /// use `clipping_layer` for exact clipping of rotated content.
     pub clip: bool,
-    /// Global space clipping, is the inverse of some parent's `RotatedRect`.
+    /// Global space clipping, the intersection of all clipping ancestors.
     /// 
     /// This occludes cursor events.
-    pub global: Option<Affine2>,
-    /// Local space clipping, between `0..=1`.
+    #[reflect(ignore)]
+    pub global: ClipRegion,
+    /// Local space clipping, between `0..=1`, `(0, 0)` being `BottomLeft`.
     /// 
-    /// Experimental, unused currently.
+    /// If set, children are clipped to this portion of the sprite's
+    /// bounding rectangle, regardless of `clip`.
     pub local: Option<Rect>,
 }
 
@@ -94,18 +156,42 @@ impl Clipping {
     pub fn new(clip: bool) -> Self {
         Clipping {
             clip,
-            global: None,
+            global: ClipRegion::UNBOUNDED,
             local: None,
         }
     }
 
-    pub fn contains(&self, pos: Vec2) -> bool {
-        match self.global {
-            Some(affine) => {
-                let vec = affine.transform_point2(pos);
-                vec.x.abs() <= 0.5 && vec.y.abs() <= 0.5
-            }
-            None => true,
+    /// Create a clipping component that clips children to a portion of this sprite.
+    pub fn local(rect: Rect) -> Self {
+        Clipping {
+            clip: true,
+            global: ClipRegion::UNBOUNDED,
+            local: Some(rect),
+        }
+    }
+
+    /// Returns true if this clips its children.
+    pub fn is_clipping(&self) -> bool {
+        self.clip || self.local.is_some()
+    }
+
+    /// Obtain the clipping region of children, given this sprite's affine.
+    pub fn children_region(&self, affine: &Affine2) -> ClipRegion {
+        if !self.is_clipping() {
+            return self.global.clone();
         }
+        let affine = match self.local {
+            Some(rect) => *affine * Affine2::from_scale_angle_translation(
+                rect.size(), 
+                0.0, 
+                rect.center() - Vec2::splat(0.5),
+            ),
+            None => *affine,
+        };
+        self.global.intersect(affine.inverse())
+    }
+
+    pub fn contains(&self, pos: Vec2) -> bool {
+        self.global.contains(pos)
     }
 }
@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bevy::prelude::*;
+
+use crate::RotatedRect;
+
+/// Explicit depth ordering of a widget, similar to CSS stacking contexts.
+///
+/// Without this component, a widget is placed at `parent_z + z`,
+/// or `parent_z + increment` if `Transform2D::z` is `0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, Default)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZIndex {
+    /// Ordered against siblings, higher is rendered above.
+    ///
+    /// With [`DepthStrategy::Hierarchy`] this offsets depth by `n` increments,
+    /// with [`DepthStrategy::Sequential`] this sorts siblings before painting.
+    Local(i32),
+    /// Moves this widget and its descendants to a global layer,
+    /// higher is rendered above.
+    ///
+    /// Widgets without a global `ZIndex` are in layer `0`.
+    ///
+    /// Layer `n` starts at depth `n * DepthBudget::layer` and is not clamped to the camera.
+    /// The default `Camera2dBundle` renders depth `-0.1..999.9`,
+    /// so with the default [`DepthBudget`] only layers `0` to `9` are visible
+    /// and negative layers are culled. Use a smaller [`DepthBudget::layer`],
+    /// or move the camera and its far plane to render more layers.
+    Global(i32),
+}
+
+impl Default for ZIndex {
+    fn default() -> Self {
+        ZIndex::Local(0)
+    }
+}
+
+impl ZIndex {
+    /// Sorting key among siblings.
+    pub fn local(&self) -> i32 {
+        match self {
+            ZIndex::Local(i) => *i,
+            ZIndex::Global(_) => 0,
+        }
+    }
+}
+
+/// How depth is computed for aoui widgets.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum DepthStrategy {
+    /// Depth is derived from the hierarchy, each level adds `increment`.
+    ///
+    /// Cheap, but descendants of a sibling might overlap a later sibling.
+    #[default]
+    Hierarchy,
+    /// Depth is derived from a depth first painting order,
+    /// distributed evenly inside each global layer.
+    ///
+    /// `Transform2D::z` is ignored with this strategy.
+    Sequential,
+}
+
+/// Depth budget of aoui widgets.
+///
+/// Global layer `n` occupies `n * layer .. (n + 1) * layer`,
+/// depth of a widget never exceeds the top of its layer,
+/// a warning is logged once if [`DepthStrategy::Hierarchy`] runs out of depth.
+#[derive(Debug, Clone, Copy, Resource, Reflect)]
+pub struct DepthBudget {
+    /// Strategy for computing depth.
+    pub strategy: DepthStrategy,
+    /// Depth added per level of hierarchy, by default `0.01`.
+    pub increment: f32,
+    /// Depth of a global layer, by default `100`.
+    ///
+    /// Global layers outside of the camera's depth range are not rendered, see [`ZIndex::Global`].
+    pub layer: f32,
+}
+
+impl Default for DepthBudget {
+    fn default() -> Self {
+        Self {
+            strategy: DepthStrategy::Hierarchy,
+            increment: 0.01,
+            layer: 100.0,
+        }
+    }
+}
+
+impl DepthBudget {
+    /// Bottom of a global layer.
+    pub fn floor(&self, layer: i32) -> f32 {
+        layer as f32 * self.layer
+    }
+
+    /// Max depth inside a global layer.
+    pub fn ceiling(&self, layer: i32) -> f32 {
+        (layer + 1) as f32 * self.layer - self.increment
+    }
+
+    /// Compute depth with [`DepthStrategy::Hierarchy`], returns depth and layer.
+    pub fn hierarchy(&self, parent_z: f32, parent_layer: i32, z: f32, z_index: Option<&ZIndex>) -> (f32, i32) {
+        let step = if z != 0.0 { z } else { self.increment };
+        let (base, layer) = match z_index {
+            Some(ZIndex::Global(layer)) => (self.floor(*layer), *layer),
+            Some(ZIndex::Local(i)) => (parent_z + *i as f32 * self.increment, parent_layer),
+            None => (parent_z, parent_layer),
+        };
+        let z = base + step;
+        let ceiling = self.ceiling(layer);
+        if z > ceiling {
+            static WARNED: AtomicBool = AtomicBool::new(false);
+            if !WARNED.swap(true, Ordering::Relaxed) {
+                warn!("Depth {} exceeds the top of global layer {} at {}, deeper widgets will overlap. \
+                    Consider increasing `DepthBudget::layer`, decreasing `DepthBudget::increment` \
+                    or using `DepthStrategy::Sequential`.", z, layer, ceiling);
+            }
+            return (ceiling, layer);
+        }
+        (z, layer)
+    }
+}
+
+/// Assign depth in painting order, if strategy is [`DepthStrategy::Sequential`].
+///
+/// Trees are walked from their topmost aoui entity,
+/// including ones parented to non-aoui entities.
+pub fn sequential_depth(
+    budget: Option<Res<DepthBudget>>,
+    roots: Query<(Entity, Option<&Parent>), With<RotatedRect>>,
+    child_query: Query<&Children>,
+    z_query: Query<&ZIndex>,
+    mut rect_query: Query<&mut RotatedRect>,
+) {
+    let Some(budget) = budget else { return };
+    if budget.strategy != DepthStrategy::Sequential { return; }
+    let key = |e: &Entity| z_query.get(*e).map(|x| x.local()).unwrap_or(0);
+
+    let mut buckets = BTreeMap::<i32, Vec<Entity>>::new();
+    let mut stack = Vec::new();
+    let mut sorted: Vec<Entity> = roots.iter()
+        .filter(|(_, parent)| parent.map(|x| !rect_query.contains(x.get())).unwrap_or(true))
+        .map(|(entity, _)| entity)
+        .collect();
+    sorted.sort_by_key(key);
+    stack.extend(sorted.into_iter().rev().map(|e| (e, 0)));
+
+    while let Some((entity, layer)) = stack.pop() {
+        let layer = match z_query.get(entity) {
+            Ok(ZIndex::Global(layer)) => *layer,
+            _ => layer,
+        };
+        buckets.entry(layer).or_default().push(entity);
+        let Ok(children) = child_query.get(entity) else { continue };
+        let mut sorted: Vec<Entity> = children.iter()
+            .copied()
+            .filter(|e| rect_query.contains(*e))
+            .collect();
+        sorted.sort_by_key(key);
+        stack.extend(sorted.into_iter().rev().map(|e| (e, layer)));
+    }
+
+    for (layer, entities) in buckets {
+        let floor = budget.floor(layer);
+        let step = (budget.ceiling(layer) - floor) / (entities.len() + 1) as f32;
+        for (index, entity) in entities.into_iter().enumerate() {
+            let Ok(mut rect) = rect_query.get_mut(entity) else { continue };
+            let z = floor + (index + 1) as f32 * step;
+            if rect.z != z {
+                rect.z = z;
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use bevy::{reflect::Reflect, ecs::{component::Component, query::WorldQuery}, math::Vec2};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::std_traits::ReflectDefault;
 
 use crate::{Size2, FontSize, SizeUnit};
 
@@ -20,6 +22,7 @@ pub enum DimensionSize {
 
 /// Controls the dimension of the sprite.
 #[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
 #[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dimension {
     /// Input for dimension.
@@ -35,6 +38,7 @@ pub struct Dimension {
 
 /// Runtime evaluated data of a widget's dimension.
 #[derive(Debug, Clone, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct DimensionData {
     /// Evaluated size in pixels.
     ///     
@@ -1,19 +1,161 @@
 use std::ops::{Mul, MulAssign};
+use std::sync::Arc;
 
 use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
 
 use crate::RotatedRect;
 
 /// Shape of a hitbox.
-#[derive(Debug, Clone, Copy, Reflect)]
+///
+/// Reflected as an opaque value, since polygons and masks are shared.
+#[derive(Debug, Clone, Reflect)]
+#[reflect_value(Debug)]
 #[non_exhaustive]
 pub enum HitboxShape{
     Rect,
     Ellipse,
+    /// A rectangle with rounded corners, radius is in world space units.
+    RoundedRect(f32),
+    /// A rounded rectangle with radius equal to half of its shorter side.
+    Capsule,
+    /// A polygon in local space, `(0, 0)` is `BottomLeft` and `(1, 1)` is `TopRight`.
+    ///
+    /// Uses the even-odd rule, so self-intersecting polygons create holes.
+    Polygon(Arc<[Vec2]>),
+    /// An alpha threshold mask generated from the sprite's `Image`.
+    AlphaMask(AlphaMask),
+}
+
+/// The image region an [`AlphaMask`] is generated from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MaskSource {
+    image: AssetId<Image>,
+    rect: Option<Rect>,
+    flip: BVec2,
+    threshold: f32,
+}
+
+/// A bitmask generated from an image's alpha channel, used by [`HitboxShape::AlphaMask`],
+/// pixels with alpha above `threshold` are considered inside.
+///
+/// Filled from the entity's `Handle<Image>` or `TextureAtlasSprite`
+/// when the image is loaded, and regenerated if the image, its region or flipping changes,
+/// or if the image asset is modified.
+/// An empty mask contains no points.
+#[derive(Debug, Clone, Default)]
+pub struct AlphaMask {
+    threshold: f32,
+    size: UVec2,
+    mask: Arc<[bool]>,
+    source: Option<MaskSource>,
+}
+
+impl AlphaMask {
+    /// Create an empty mask, generated when the sprite's image is loaded.
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            ..Default::default()
+        }
+    }
+
+    /// Alpha threshold of the mask.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Returns true if the mask is not generated or the image is not supported.
+    pub fn is_empty(&self) -> bool {
+        self.mask.is_empty()
+    }
+
+    /// Clear the mask, forcing it to be regenerated.
+    pub fn clear(&mut self) {
+        self.size = UVec2::ZERO;
+        self.mask = Arc::new([]);
+        self.source = None;
+    }
+
+    /// Returns true if the mask is already generated from this source,
+    /// including failed attempts on unsupported images.
+    pub(crate) fn is_generated_from(&self, image: AssetId<Image>, rect: Option<Rect>, flip: BVec2) -> bool {
+        self.source == Some(MaskSource { image, rect, flip, threshold: self.threshold })
+    }
+
+    /// Generate the mask from an image, cropped to `rect` in pixels if specified and flipped by `flip`.
+    ///
+    /// Returns false and clears the mask if the image's format is not supported,
+    /// currently supported formats are 8 bit `RGBA` and `BGRA`.
+    pub fn generate(&mut self, id: AssetId<Image>, image: &Image, rect: Option<Rect>, flip: BVec2) -> bool {
+        self.size = UVec2::ZERO;
+        self.mask = Arc::new([]);
+        self.source = Some(MaskSource { image: id, rect, flip, threshold: self.threshold });
+        match image.texture_descriptor.format {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb |
+            TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => (),
+            _ => return false,
+        }
+        let width = image.texture_descriptor.size.width;
+        let height = image.texture_descriptor.size.height;
+        let rect = rect.unwrap_or(Rect::new(0.0, 0.0, width as f32, height as f32));
+        let min = rect.min.max(Vec2::ZERO).as_uvec2();
+        let max = rect.max.as_uvec2().min(UVec2::new(width, height));
+        if min.x >= max.x || min.y >= max.y {
+            return false;
+        }
+        let threshold = (self.threshold.clamp(0.0, 1.0) * 255.0) as u8;
+        self.size = max - min;
+        self.mask = (0..self.size.y).flat_map(|y| (0..self.size.x).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let x = if flip.x { max.x - 1 - x } else { min.x + x };
+                let y = if flip.y { max.y - 1 - y } else { min.y + y };
+                let index = (y * width + x) as usize * 4 + 3;
+                image.data.get(index).map(|a| *a > threshold).unwrap_or(false)
+            })
+            .collect();
+        true
+    }
+
+    /// Sample the mask with a point in local space, `(0, 0)` is `BottomLeft`.
+    pub fn contains(&self, point: Vec2) -> bool {
+        if self.is_empty() || point.cmplt(Vec2::ZERO).any() || point.cmpge(Vec2::ONE).any() {
+            return false;
+        }
+        // images are stored top to bottom.
+        let x = (point.x * self.size.x as f32) as u32;
+        let y = ((1.0 - point.y) * self.size.y as f32) as u32;
+        let x = x.min(self.size.x - 1);
+        let y = y.min(self.size.y - 1);
+        self.mask[(y * self.size.x + x) as usize]
+    }
+}
+
+/// Even-odd point in polygon test.
+fn polygon_contains(polygon: &[Vec2], point: Vec2) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len().wrapping_sub(1);
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[j];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Signed distance to a rounded rectangle centered at origin.
+fn rounded_rect_distance(point: Vec2, half: Vec2, radius: f32) -> f32 {
+    let radius = radius.clamp(0.0, half.min_element());
+    let q = point.abs() - half + radius;
+    q.max(Vec2::ZERO).length() + q.max_element().min(0.0) - radius
 }
 
 /// Provides cursor detection on [`RotatedRect`]
-#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct Hitbox {
     pub shape: HitboxShape,
     pub scale: Vec2,
@@ -52,19 +194,72 @@ impl Hitbox {
         }
     }
 
+    pub fn rounded_rect(radius: f32) -> Self {
+        Self {
+            shape: HitboxShape::RoundedRect(radius),
+            scale: Vec2::ONE,
+        }
+    }
+
+    pub fn capsule() -> Self {
+        Self {
+            shape: HitboxShape::Capsule,
+            scale: Vec2::ONE,
+        }
+    }
+
+    pub fn polygon(points: impl Into<Arc<[Vec2]>>) -> Self {
+        Self {
+            shape: HitboxShape::Polygon(points.into()),
+            scale: Vec2::ONE,
+        }
+    }
+
+    pub fn alpha_mask(threshold: f32) -> Self {
+        Self {
+            shape: HitboxShape::AlphaMask(AlphaMask::new(threshold)),
+            scale: Vec2::ONE,
+        }
+    }
+
+    /// Check if a screen space point is inside the hitbox.
+    /// 
+    /// This is evaluated in the rect's local space,
+    /// so rotation, scale and skew are all respected.
     pub fn contains(&self, rect: &RotatedRect, point: Vec2) -> bool {
-        let local = point - rect.center();
-        let x = rect.affine.transform_vector2(Vec2::new(0.5, 0.0)) / self.scale.x;
-        let y = rect.affine.transform_vector2(Vec2::new(0.0, 0.5)) / self.scale.y;
-        match self.shape {
+        let local = rect.affine.inverse().transform_point2(point) / self.scale;
+        match &self.shape {
             HitboxShape::Rect => {
-                local.dot(x).abs() < x.length_squared() && local.dot(y).abs() < y.length_squared()
+                local.x.abs() < 0.5 && local.y.abs() < 0.5
             },
             HitboxShape::Ellipse => {
-                local.dot(x).abs() / x.length_squared() + local.dot(y).abs() / y.length_squared() <= 1.0
-            }
+                (local * 2.0).length_squared() <= 1.0
+            },
+            HitboxShape::RoundedRect(radius) => {
+                // Corners must be round in world space, not in the unit square.
+                let size = Self::world_size(rect) * self.scale;
+                rounded_rect_distance(local * size, size / 2.0, *radius) <= 0.0
+            },
+            HitboxShape::Capsule => {
+                let size = Self::world_size(rect) * self.scale;
+                rounded_rect_distance(local * size, size / 2.0, size.min_element() / 2.0) <= 0.0
+            },
+            HitboxShape::Polygon(polygon) => {
+                polygon_contains(polygon, local + Vec2::splat(0.5))
+            },
+            HitboxShape::AlphaMask(mask) => {
+                mask.contains(local + Vec2::splat(0.5))
+            },
         }
     }
+
+    /// Size of the rect in world space, before rotation.
+    fn world_size(rect: &RotatedRect) -> Vec2 {
+        Vec2::new(
+            rect.affine.matrix2.x_axis.length(),
+            rect.affine.matrix2.y_axis.length(),
+        )
+    }
 }
 
 impl Mul<Vec2> for Hitbox {
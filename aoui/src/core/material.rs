@@ -0,0 +1,60 @@
+use std::{hash::Hash, marker::PhantomData};
+
+use bevy::prelude::*;
+use bevy::sprite::{Material2d, Material2dPlugin};
+
+use crate::{Opacity, IgnoreAlpha};
+use crate::anim::{Interpolate, InterpolationSet};
+use crate::schedule::AouiStoreOutputSet;
+
+/// A [`Material2d`] that reacts to aoui's [`Opacity`] and `Interpolate<Color>`.
+///
+/// Register with [`AouiMaterialPlugin`], materials used in
+/// [`AouiMaterialMesh2dBundle`](crate::bundles::AouiMaterialMesh2dBundle)
+/// then need no bespoke systems.
+pub trait AouiMaterial: Material2d {
+    /// Set computed opacity, usually the alpha of the material's color.
+    fn set_opacity(&mut self, opacity: f32);
+    /// Set color of the material.
+    fn set_color(&mut self, color: Color);
+}
+
+/// Copy opacity to [`AouiMaterial`]s.
+pub fn sync_opacity_material<M: AouiMaterial>(
+    query: Query<(&Handle<M>, &Opacity), Without<IgnoreAlpha>>,
+    mut assets: ResMut<Assets<M>>
+) {
+    for (handle, opacity) in query.iter() {
+        let Some(asset) = assets.get_mut(handle) else { continue };
+        asset.set_opacity(opacity.get());
+    }
+}
+
+/// Copy `Interpolate<Color>` to [`AouiMaterial`]s.
+pub fn interpolate_color_material<M: AouiMaterial>(
+    query: Query<(&Interpolate<Color>, &Handle<M>)>,
+    mut assets: ResMut<Assets<M>>
+) {
+    for (interpolate, handle) in query.iter() {
+        let Some(asset) = assets.get_mut(handle) else { continue };
+        asset.set_color(interpolate.get());
+    }
+}
+
+/// Adds a [`Material2dPlugin`] and aoui integration for an [`AouiMaterial`].
+#[derive(Debug)]
+pub struct AouiMaterialPlugin<M: AouiMaterial>(PhantomData<M>);
+
+impl<M: AouiMaterial> Default for AouiMaterialPlugin<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M: AouiMaterial> Plugin for AouiMaterialPlugin<M> where M::Data: PartialEq + Eq + Hash + Clone {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(Material2dPlugin::<M>::default())
+            .add_systems(Update, interpolate_color_material::<M>.in_set(InterpolationSet))
+            .add_systems(PostUpdate, sync_opacity_material::<M>.in_set(AouiStoreOutputSet));
+    }
+}
@@ -7,11 +7,15 @@ pub(crate) mod pipeline;
 pub(crate) mod scaling;
 pub(crate) mod systems;
 pub(crate) mod transform;
+pub(crate) mod depth;
+pub(crate) mod material;
 
 pub use rect::*;
 pub use components::*;
 pub use hitbox::*;
 pub use scaling::*;
+pub use depth::{ZIndex, DepthStrategy, DepthBudget};
+pub use material::{AouiMaterial, AouiMaterialPlugin};
 
 pub use transform::{Transform2D, BuildTransform, BuildMeshTransform};
 pub use dimension::{Dimension, DimensionData, DimensionSize, DimensionMut};
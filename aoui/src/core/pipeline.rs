@@ -11,8 +11,6 @@ type AouiEntity<'t> = (
     &'t mut Clipping,
 );
 
-const Z_INCREMENT: f32 = 0.01;
-
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::needless_pass_by_ref_mut)]
 fn propagate<TAll: ReadOnlyWorldQuery>(
@@ -24,6 +22,8 @@ fn propagate<TAll: ReadOnlyWorldQuery>(
     parent_query: &Query<&Parent>,
     child_query: &Query<&Children>,
     control_query: &Query<&LayoutControl>,
+    z_query: &Query<&ZIndex>,
+    budget: &DepthBudget,
     queue: &mut Vec<(Entity, ParentInfo)>) {
 
     if !mut_query.contains(entity) { return; }
@@ -39,7 +39,14 @@ fn propagate<TAll: ReadOnlyWorldQuery>(
     let (dimension, em) = dim.update(parent.dimension, parent.em, rem);
     let offset = transform.offset.as_pixels(parent.dimension, em, rem);
     
-    clipping.global = parent.clip;
+    clipping.global = parent.clip.clone();
+
+    let (z, z_layer) = budget.hierarchy(parent.rect.z, parent.z_layer, transform.z, z_query.get(entity).ok());
+    // Sequential depth is written in a later pass, keep the old value to avoid change detection.
+    let z = match budget.strategy {
+        DepthStrategy::Hierarchy => z,
+        DepthStrategy::Sequential => orig.z,
+    };
 
     opacity.occluded = false;
     opacity.computed_opacity = opacity.opacity * parent.opacity;
@@ -105,11 +112,8 @@ fn propagate<TAll: ReadOnlyWorldQuery>(
             transform.get_center(),
             transform.rotation,
             transform.scale,
-            if transform.z != 0.0 {
-                parent.rect.z + transform.z
-            } else {
-                parent.rect.z + Z_INCREMENT
-            }        
+            transform.skew,
+            z,
         );
 
         let info = ParentInfo {
@@ -120,15 +124,16 @@ fn propagate<TAll: ReadOnlyWorldQuery>(
             em,
             opacity,
             disabled,
-            clip: if clipping.clip {Some(rect.affine.inverse())} else {parent.clip},
+            clip: clipping.children_region(&rect.affine),
+            z_layer,
         };
 
-        queue.extend(entity_anchors.into_iter().map(|(e, anc)| (e, info.with_anchor(anc))));
+        queue.extend(entity_anchors.into_iter().map(|(e, anc)| (e, info.clone().with_anchor(anc))));
         if orig.as_ref() != &rect {
             *orig = rect
         }
         for (child, _) in other_entities {
-            queue.push((child, info))
+            queue.push((child, info.clone()))
         }
         return;
     }
@@ -143,11 +148,8 @@ fn propagate<TAll: ReadOnlyWorldQuery>(
         transform.get_center(),
         transform.rotation,
         transform.scale,
-        if transform.z != 0.0 {
-            parent.rect.z + transform.z
-        } else {
-            parent.rect.z + Z_INCREMENT
-        }
+        transform.skew,
+        z,
     );
     
 
@@ -160,10 +162,11 @@ fn propagate<TAll: ReadOnlyWorldQuery>(
             em,
             opacity,
             disabled,
-            clip: if clipping.clip {Some(rect.affine.inverse())} else {parent.clip},
+            clip: clipping.children_region(&rect.affine),
+            z_layer,
         };
         for child in children {
-            queue.push((*child, info))
+            queue.push((*child, info.clone()))
         }
     }
 
@@ -196,6 +199,7 @@ impl<'t> RootQuery<'t> for PrimaryWindow {
             affine: Affine2::from_scale(dim),
             rotation: 0.0,
             scale: Vec2::ONE,
+            skew: Vec2::ZERO,
             z: 0.0
         }, dim)
     }
@@ -222,9 +226,12 @@ pub fn compute_aoui_transforms<'t, R: RootQuery<'t>, TRoot: ReadOnlyWorldQuery,
     parent_query: Query<&Parent>,
     child_query: Query<&Children>,
     control_query: Query<&LayoutControl>,
+    z_query: Query<&ZIndex>,
     res_rem: Option<Res<AouiREM>>,
+    res_budget: Option<Res<DepthBudget>>,
 ) {
     let rem = res_rem.map(|x| x.get()).unwrap_or(16.0);
+    let budget = res_budget.map(|x| *x).unwrap_or_default();
 
     let (window_rect, dimension) = R::as_rect(&root);
 
@@ -237,11 +244,12 @@ pub fn compute_aoui_transforms<'t, R: RootQuery<'t>, TRoot: ReadOnlyWorldQuery,
         em: rem,
         opacity: 1.0,
         disabled: false,
-        clip: None,
+        clip: ClipRegion::UNBOUNDED,
+        z_layer: 0,
     };
 
     for (entity, ..) in entity_query.iter_many(root_entities.iter()) {
-        queue.push((entity, window_info))
+        queue.push((entity, window_info.clone()))
     }
 
     while !queue.is_empty() {
@@ -254,6 +262,8 @@ pub fn compute_aoui_transforms<'t, R: RootQuery<'t>, TRoot: ReadOnlyWorldQuery,
                 &parent_query, 
                 &child_query, 
                 &control_query, 
+                &z_query,
+                &budget,
                 &mut queue
             );
         }
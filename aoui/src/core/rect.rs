@@ -1,7 +1,11 @@
 
 use std::ops::Mul;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::std_traits::ReflectDefault;
 
-use bevy::{math::{Vec2, Affine2, Rect}, reflect::Reflect, prelude::Component, ecs::entity::Entity, };
+use bevy::{math::{Vec2, Mat2, Affine2, Rect}, reflect::Reflect, prelude::Component, ecs::entity::Entity, };
+
+use crate::ClipRegion;
 
 /// Anchor of a sprite, this is a more concise implementation than bevy's.
 /// 
@@ -106,6 +110,7 @@ impl From<&Anchor> for bevy::sprite::Anchor {
 /// 
 /// Note: `scale` is pre-multiplied into `dimension`.
 #[derive(Debug, Clone, Copy, Component, PartialEq, Default, Reflect)]
+#[reflect(Component, Default)]
 #[non_exhaustive]
 pub struct RotatedRect{
     /// Affine of the rect.
@@ -116,6 +121,8 @@ pub struct RotatedRect{
     pub z: f32,
     /// Scale of the rect.
     pub scale: Vec2,
+    /// Accumulated skew of the rect.
+    pub skew: Vec2,
 }
 
 #[doc(hidden)]
@@ -127,7 +134,7 @@ pub enum PointOrRect {
 
 /// Relevant info about an Aoui sprite's parent.
 #[doc(hidden)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct ParentInfo {
     pub entity: Option<Entity>,
     pub rect: RotatedRect,
@@ -135,8 +142,9 @@ pub struct ParentInfo {
     pub dimension: Vec2,
     pub em: f32,
     pub opacity: f32,
-    pub clip: Option<Affine2>,
+    pub clip: ClipRegion,
     pub disabled: bool,
+    pub z_layer: i32,
 }
 
 impl ParentInfo {
@@ -166,16 +174,38 @@ impl RotatedRect {
         self.affine.translation
     }
 
+    /// Screen space corners of the rect, in order `BottomLeft`, `BottomRight`, `TopRight`, `TopLeft`.
+    #[inline]
+    pub fn corners(&self) -> [Vec2; 4] {
+        [
+            self.anchor(Anchor::BottomLeft),
+            self.anchor(Anchor::BottomRight),
+            self.anchor(Anchor::TopRight),
+            self.anchor(Anchor::TopLeft),
+        ]
+    }
+
     /// convert a screen space point to local space, centered on `Center`.
     #[inline]
     pub fn local_space(&self, position: Vec2) -> Vec2 {
-        Vec2::from_angle(-self.rotation).rotate(position - self.center())
+        let linear = Mat2::from_angle(self.rotation) * shear_matrix(self.skew);
+        linear.inverse() * (position - self.center())
+    }
+
+    /// The linear part of the `GlobalTransform`, rotation, skew and scale, without dimension.
+    #[inline]
+    pub fn linear_transform(&self) -> Mat2 {
+        Mat2::from_angle(self.rotation) * shear_matrix(self.skew) * Mat2::from_diagonal(self.scale)
     }
 
     /// Create an [`RotatedRect`] representing the sprite's position on the screen space
     /// and an `Affine3A` that converts into the `GlobalTransform` suitable from the screen space
+    /// 
+    /// Skew is accumulated additively, which is exact only if skewed ancestors
+    /// skew along the same single axis, with no rotation or non-uniform scale between them,
+    /// otherwise this is an approximation.
     pub fn construct(parent: &ParentInfo, parent_anchor: Anchor, anchor: Anchor, offset: Vec2, dim: Vec2,
-            center: Anchor, rotation: f32, scale: Vec2, z: f32) -> Self{
+            center: Anchor, rotation: f32, scale: Vec2, skew: Vec2, z: f32) -> Self{
         let parent_anchor = parent.anchor.unwrap_or_else(|| 
             parent.rect.affine.transform_point2(parent_anchor.or(anchor).as_vec())
         );
@@ -183,24 +213,45 @@ impl RotatedRect {
         let self_center = offset + (center.as_vec() - anchor.as_vec()) * dim;
         let dir = (Anchor::Center.as_vec() - center.as_vec()) * dim;
 
-        let out_center = Vec2::from_angle(parent.rect.rotation).rotate(self_center * parent.rect.scale) + parent_anchor;
+        let parent_linear = Mat2::from_angle(parent.rect.rotation) * shear_matrix(parent.rect.skew);
+        let out_center = parent_linear * (self_center * parent.rect.scale) + parent_anchor;
         let rotation = parent.rect.rotation + rotation;
         let scale = parent.rect.scale * scale;
-        let out_origin = out_center + Vec2::from_angle(rotation).rotate(dir * scale);
+        let skew = parent.rect.skew + skew;
+        let linear = Mat2::from_angle(rotation) * shear_matrix(skew);
+        let out_origin = out_center + linear * (dir * scale);
 
         Self {
-            affine: Affine2::from_scale_angle_translation(
-                dim * scale, 
-                rotation, 
+            affine: Affine2::from_mat2_translation(
+                linear * Mat2::from_diagonal(dim * scale), 
                 out_origin
             ),
             z,
             rotation,
             scale,
+            skew,
         }
     }
 }
 
+/// Minimum determinant `1 - skew.x * skew.y` of a shear matrix.
+const MIN_SHEAR_DETERMINANT: f32 = 0.001;
+
+/// Matrix for shearing `x` by `skew.x * y` and `y` by `skew.y * x`.
+///
+/// If `skew.x * skew.y` is close to `1` the matrix collapses the plane and cannot be inverted,
+/// in which case skew is scaled down to keep the matrix invertible.
+#[inline]
+pub(crate) fn shear_matrix(skew: Vec2) -> Mat2 {
+    let product = skew.x * skew.y;
+    let skew = if (1.0 - product).abs() < MIN_SHEAR_DETERMINANT {
+        skew * ((1.0 - MIN_SHEAR_DETERMINANT) / product).sqrt()
+    } else {
+        skew
+    };
+    Mat2::from_cols(Vec2::new(1.0, skew.y), Vec2::new(skew.x, 1.0))
+}
+
 #[cfg(feature="serde")]
 const _: () = {
     use serde::{Serialize, Deserialize};
@@ -209,7 +260,8 @@ const _: () = {
             let [a, b, c, d, e, f] = self.affine.to_cols_array();
             [a, b, c, d, e, f, 
                 self.rotation, self.z, 
-                self.scale.x, self.scale.y
+                self.scale.x, self.scale.y,
+                self.skew.x, self.skew.y,
             ].serialize(serializer)
         }
     }
@@ -217,11 +269,12 @@ const _: () = {
 
     impl<'de> Deserialize<'de> for RotatedRect {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
-            let [a,b,c,d,e,f,r,z,sx,sy] = <_>::deserialize(deserializer)?; 
+            let [a,b,c,d,e,f,r,z,sx,sy,kx,ky] = <_>::deserialize(deserializer)?; 
             Ok(Self { 
                 affine: Affine2::from_cols_array(&[a, b, c, d, e, f]),
                 rotation: r, z,
                 scale: Vec2::new(sx, sy),
+                skew: Vec2::new(kx, ky),
             })
         }
     }
@@ -1,11 +1,11 @@
-use bevy::math::Affine3A;
+use bevy::math::{Affine3A, Mat2, Mat3};
 use bevy::sprite::{Sprite, TextureAtlasSprite};
 use bevy::text::{TextLayoutInfo, Text2dBounds};
 use bevy::prelude::*;
 
 use bevy::sprite::Anchor as BevyAnchor;
 use crate::dimension::DimensionMut;
-use crate::{RotatedRect, BuildTransform, Transform2D, Opacity, IgnoreAlpha, BuildMeshTransform, Anchor, DimensionData, Dimension};
+use crate::{Hitbox, HitboxShape, RotatedRect, BuildTransform, Transform2D, Opacity, IgnoreAlpha, BuildMeshTransform, Anchor, DimensionData, Dimension};
 
 
 /// Copy our `anchor` component's value to the `Anchor` component
@@ -43,6 +43,38 @@ pub fn copy_dimension_sprite(mut query: Query<(&Sprite, &Handle<Image>, Dimensio
     })
 }
 
+/// Generate [`AlphaMask`](crate::AlphaMask)s of `AlphaMask` hitboxes from the sprite's image or atlas region.
+pub fn copy_alpha_mask(
+    mut events: EventReader<AssetEvent<Image>>,
+    mut query: Query<(&mut Hitbox,
+        Option<&Sprite>, Option<&Handle<Image>>,
+        Option<&TextureAtlasSprite>, Option<&Handle<TextureAtlas>>,
+    )>,
+    images: Res<Assets<Image>>,
+    atlases: Res<Assets<TextureAtlas>>,
+) {
+    let modified: Vec<_> = events.read().filter_map(|event| match event {
+        AssetEvent::Modified { id } => Some(*id),
+        _ => None,
+    }).collect();
+    for (mut hitbox, sprite, image, atlas_sprite, atlas) in query.iter_mut() {
+        let HitboxShape::AlphaMask(mask) = &hitbox.shape else { continue };
+        let (id, rect, flip) = match (sprite, image, atlas_sprite, atlas) {
+            (Some(sprite), Some(image), ..) => (image.id(), sprite.rect, BVec2::new(sprite.flip_x, sprite.flip_y)),
+            (.., Some(sprite), Some(atlas)) => {
+                let Some(atlas) = atlases.get(atlas) else { continue };
+                (atlas.texture.id(), atlas.textures.get(sprite.index).copied(), BVec2::new(sprite.flip_x, sprite.flip_y))
+            },
+            _ => continue,
+        };
+        if mask.is_generated_from(id, rect, flip) && !modified.contains(&id) { continue; }
+        let Some(image) = images.get(id) else { continue };
+        if let HitboxShape::AlphaMask(mask) = &mut hitbox.shape {
+            mask.generate(id, image, rect, flip);
+        }
+    }
+}
+
 /// Copy anchor to the `TextureAtlasSprite` component
 pub fn copy_anchor_atlas(mut query: Query<(&mut TextureAtlasSprite, &Transform2D)>) {
     query.iter_mut().for_each(|(mut sp, anc)| {
@@ -95,11 +127,13 @@ pub fn sync_dimension_text_bounds(mut query: Query<(&mut Text2dBounds, &Dimensio
 
 
 /// Opts out of synchronizing text bounds.
-#[derive(Debug, Component)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct OptOutTextBoundsSync;
 
 /// Opts out of synchronizing font size.
-#[derive(Debug, Component)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct OptOutFontSizeSync;
 
 
@@ -142,9 +176,8 @@ pub fn build_mesh_2d_global_transform(
     mut query: Query<(&RotatedRect, &DimensionData, &mut GlobalTransform), With<BuildMeshTransform>>
 ) {
     query.iter_mut().for_each(|(rect, dim, mut transform)| 
-        *transform = Affine3A::from_scale_rotation_translation(
-            (rect.scale * dim.size).extend(1.0), 
-            Quat::from_rotation_z(rect.rotation), 
+        *transform = Affine3A::from_mat3_translation(
+            Mat3::from_mat2(rect.linear_transform() * Mat2::from_diagonal(dim.size)), 
             rect.anchor(Anchor::Center).extend(rect.z)
         ).into()
     );
@@ -155,9 +188,8 @@ pub fn build_global_transform(
     mut query: Query<(&BuildTransform, &Transform2D, &RotatedRect, &mut GlobalTransform)>,
 ) {
     query.iter_mut().for_each(|(build, transform, rect, mut global)| {
-        *global = Affine3A::from_scale_rotation_translation(
-            rect.scale.extend(1.0), 
-            Quat::from_rotation_z(rect.rotation), 
+        *global = Affine3A::from_mat3_translation(
+            Mat3::from_mat2(rect.linear_transform()), 
             rect.anchor(build.0.or(transform.anchor)).extend(rect.z)
         ).into()
     });
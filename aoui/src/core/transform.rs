@@ -1,9 +1,12 @@
 use bevy::{reflect::Reflect, ecs::component::Component, math::Vec2};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::std_traits::ReflectDefault;
 
 use crate::{Anchor, Size2};
 
 /// The 2D transform component for Aoui
 #[derive(Debug, Copy, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct Transform2D{
     /// The sprite's offset, as well as
     /// parent rotation and parent scale
@@ -32,6 +35,10 @@ pub struct Transform2D{
     pub rotation: f32,
     /// Scaling around `center`.
     pub scale: Vec2,
+    /// Shear factors in local space, applied after `scale` and before `rotation`.
+    ///
+    /// `x` shifts `x` by `skew.x * y`, `y` shifts `y` by `skew.y * x`.
+    pub skew: Vec2,
 }
 
 impl Transform2D {
@@ -52,6 +59,7 @@ impl Transform2D {
         rotation: 0.0,
         z: 0.0,
         scale: Vec2::ONE,
+        skew: Vec2::ZERO,
     };
 
     /// Set offset.
@@ -72,6 +80,12 @@ impl Transform2D {
         self
     }
 
+    /// Set skew.
+    pub fn with_skew(mut self, skew: Vec2) -> Self {
+        self.skew = skew;
+        self
+    }
+
     /// Set z offset.
     pub fn with_z(mut self, z: f32) -> Self {
         self.z = z;
@@ -107,6 +121,7 @@ impl Default for Transform2D {
 
 /// Builds a `GlobalTransform` on a `Anchor`, by default `Transform2D::anchor`.
 #[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct BuildTransform(pub Anchor);
 
 impl Default for BuildTransform {
@@ -119,4 +134,5 @@ impl Default for BuildTransform {
 /// Builds a `GlobalTransform` for `Mesh2d`, 
 /// this always uses `Anchor::Center` and converts dimension to scale.
 #[derive(Debug, Clone, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct BuildMeshTransform;
@@ -8,6 +8,8 @@ widget_extension!(
     pub struct CameraFrameBuilder {
         /// Render target of the camera.
         pub render_target: Option<Handle<Image>>,
+        /// If set, resize the render target to the frame's pixel size times this value.
+        pub auto_resize: Option<f32>,
     }
 );
 
@@ -16,10 +18,13 @@ impl Widget for CameraFrameBuilder {
         let Some(buffer) = self.render_target else  {panic!("Requires \"buffer\"")};
         let entity = build_frame!(commands, self).id();
 
-        let bundle = ScopedCameraBundle::from_image(
+        let mut bundle = ScopedCameraBundle::from_image(
             buffer,
             self.layer.expect("Please specify a render layer.")
         );
+        if let Some(scale) = self.auto_resize {
+            bundle = bundle.with_auto_resize(scale);
+        }
         commands.entity(entity).insert(bundle);
 
         (entity, entity)
@@ -78,9 +78,12 @@ impl Widget for FrameBuilder {
         if let Some(layer) = self.layer {
             base.insert(layer);
         }
+        if let Some(z_index) = self.z_index {
+            base.insert(z_index);
+        }
         if let Some(layout) = self.layout {
             base.insert(Container {
-                layout,
+                layout: layout.into(),
                 margin: self.margin.0,
                 padding: self.padding.0,
                 range: self.children_range,
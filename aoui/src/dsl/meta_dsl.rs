@@ -416,6 +416,7 @@ macro_rules! transform2d {
                 Some($crate::dsl::prelude::OneOrTwo(vec)) => vec,
                 None => $crate::bevy::math::Vec2::ONE,
             },
+            skew: $this.skew,
             z: $this.z
         }
     };
@@ -473,8 +474,12 @@ macro_rules! widget_extension {
             pub rotation: f32,
             /// Scale of the sprite from `center`.
             pub scale: Option<$crate::dsl::OneOrTwo<$crate::bevy::math::Vec2>>,
+            /// Skew of the sprite from `center`.
+            pub skew: $crate::bevy::math::Vec2,
             /// Z depth of the sprite.
             pub z: f32,
+            /// Explicit depth ordering of the sprite.
+            pub z_index: Option<$crate::ZIndex>,
             /// If true, clips its children, currently only affects events.
             pub clipping: Option<bool>,
             /// Owned dimension of the sprite.
@@ -523,11 +528,13 @@ macro_rules! build_frame {
                 offset: $this.offset,
                 rotation: $this.rotation,
                 scale: $this.scale,
+                skew: $this.skew,
                 z: $this.z,
+                z_index: $this.z_index,
                 dimension: $this.dimension,
                 font_size: $this.font_size,
                 event: $this.event,
-                hitbox: $this.hitbox,
+                hitbox: $this.hitbox.clone(),
                 layer: $this.layer,
                 aspect: $this.aspect,
                 clipping: $this.clipping,
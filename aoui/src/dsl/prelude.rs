@@ -29,11 +29,18 @@ pub use crate::events::{
     EvLeftPressed, EvLeftDrag,
     EvMidPressed, EvMidDrag,
     EvRightPressed, EvRightDrag,
+    EvOtherDown, EvOtherClick,
+    EvOtherPressed, EvOtherDrag,
 
     EvButtonClick, EvToggleChange,
     EvObtainFocus, EvLoseFocus,
+    EvObtainKeyboardFocus, EvLoseKeyboardFocus, Focusable,
+    EvLongPress, EvHoldRepeat, EvHoldProgress, HoldThreshold,
+    EvDragEnter, EvDragLeave, EvDropReceived,
+    EvShortcut, Shortcuts, KeyChord, ShortcutLabel,
+    EvPointerCaptureLost,
     EvMouseDrag, EvTextChange, EvTextSubmit,
-    EvPositionFactor,
+    EvPositionFactor, EvPageChanged,
     Handlers, Handler, OneShot, Mutation
 };
 pub use crate::signals::{channel, fps_channel, SignalSender, SignalReceiver};
@@ -43,8 +50,9 @@ pub use crate::widgets::button::{
     PropagateFocus, DisplayIf, SetCursor, CheckButtonState, radio_button_group,
     CheckButton, RadioButton
 };
-pub use crate::widgets::scroll::{Scrolling, IntoScrollingBuilder};
+pub use crate::widgets::scroll::{Scrolling, ScrollPhysics, ScrollSnap, SnapPoints, IntoScrollingBuilder};
 pub use crate::widgets::drag::IntoDraggingBuilder;
+pub use crate::widgets::dnd::{DragPayload, AcceptDrop};
 
 pub const FlipX: [bool; 2] = [true, false];
 pub const FlipY: [bool; 2] = [false, true];
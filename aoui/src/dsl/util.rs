@@ -27,7 +27,7 @@ impl<T: DslInto<OneOrTwo<Vec2>>> DslInto<Option<Hitbox>> for DslHitbox<T> {
                 scale: scale.dinto().0,
             },
             DslHitbox::Ellipse(scale) =>  Hitbox {
-                shape: HitboxShape::Rect,
+                shape: HitboxShape::Ellipse,
                 scale: scale.dinto().0,
             },
         })
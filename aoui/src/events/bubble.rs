@@ -0,0 +1,131 @@
+use bevy::ecs::{entity::Entity, component::Component, world::World};
+use bevy::ecs::system::{Commands, CommandQueue};
+use bevy::ecs::query::Without;
+use bevy::hierarchy::Parent;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+
+use crate::signals::KeyStorage;
+
+use super::{CursorAction, CursorState, EventFlags, EventHandling, Handlers};
+use super::{EvLeftDown, EvLeftClick, EvDoubleClick, EvMidDown, EvMidClick, EvRightDown, EvRightClick, EvDragEnd, EvDrop};
+use super::{EvOtherDown, EvOtherClick};
+
+/// Receive [`CursorAction`]s bubbled up from descendants.
+///
+/// Actions travel up the `Parent` chain from the widget that received them,
+/// running `Handlers` of ancestors with `EventBubbling` that contains the action,
+/// until an [`EventConsumed`] is found.
+///
+/// Bubbled actions do not insert `CursorAction` on ancestors,
+/// only `Handlers` of the corresponding events are called,
+/// `EvOther*` handlers receive the index of `MouseButton::Other`.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct EventBubbling(pub EventFlags);
+
+impl EventBubbling {
+    /// Receive all bubbled actions.
+    pub const ALL: Self = Self(EventFlags::All);
+
+    /// Returns true if `flags` should be received by this entity.
+    pub fn accepts(&self, flags: EventFlags) -> bool {
+        self.0.intersects(flags)
+    }
+}
+
+/// Insert this on an entity during its event handlers to stop
+/// the current [`CursorAction`] from bubbling further up.
+///
+/// Removed at the end of the frame.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct EventConsumed;
+
+/// Stores the origin of a bubbled [`CursorAction`] on an ancestor,
+/// available to its handlers.
+///
+/// Removed at the end of the frame.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct BubbledAction {
+    /// The widget that originally received the action.
+    pub origin: Entity,
+    /// The action.
+    pub flags: EventFlags,
+}
+
+impl Default for BubbledAction {
+    fn default() -> Self {
+        Self { origin: Entity::PLACEHOLDER, flags: EventFlags::default() }
+    }
+}
+
+/// Run handlers of an event immediately.
+fn run_handlers<T: EventHandling>(world: &mut World, entity: Entity, data: T::Data) {
+    let mut queue = CommandQueue::default();
+    {
+        let (Some(handlers), Some(keys)) = (world.get::<Handlers<T>>(entity), world.get_resource::<KeyStorage>()) else {
+            return;
+        };
+        let mut commands = Commands::new(&mut queue, world);
+        handlers.handle(&mut commands.entity(entity), keys, data);
+    }
+    queue.apply(world);
+}
+
+fn run_action_handlers(world: &mut World, entity: Entity, flags: EventFlags) {
+    macro_rules! dispatch {
+        ($($flag: ident => $ty: ty),* $(,)?) => {
+            $(if flags == EventFlags::$flag {
+                return run_handlers::<$ty>(world, entity, ());
+            })*
+        };
+    }
+    let button = world.get_resource::<CursorState>().map(|x| x.other_button()).unwrap_or(0);
+    if flags == EventFlags::OtherDown {
+        return run_handlers::<EvOtherDown>(world, entity, button);
+    }
+    if flags == EventFlags::OtherClick {
+        return run_handlers::<EvOtherClick>(world, entity, button);
+    }
+    dispatch!(
+        LeftDown => EvLeftDown,
+        LeftClick => EvLeftClick,
+        DoubleClick => EvDoubleClick,
+        MidDown => EvMidDown,
+        MidClick => EvMidClick,
+        RightDown => EvRightDown,
+        RightClick => EvRightClick,
+        DragEnd => EvDragEnd,
+        Drop => EvDrop,
+    );
+}
+
+/// Bubble [`CursorAction`]s up to ancestors with [`EventBubbling`].
+///
+/// This runs after handlers of the original widget,
+/// so they can insert [`EventConsumed`] to prevent bubbling.
+pub fn bubble_cursor_action(world: &mut World) {
+    let origins: Vec<_> = world.query_filtered::<(Entity, &CursorAction), Without<EventConsumed>>()
+        .iter(world)
+        .map(|(entity, action)| (entity, action.flags()))
+        .collect();
+    for (origin, flags) in origins {
+        let mut current = origin;
+        while let Some(parent) = world.get::<Parent>(current) {
+            current = parent.get();
+            let accepts = world.get::<EventBubbling>(current)
+                .map(|x| x.accepts(flags))
+                .unwrap_or(false);
+            if !accepts { continue; }
+            world.entity_mut(current).insert(BubbledAction { origin, flags });
+            run_action_handlers(world, current, flags);
+            if world.get::<EventConsumed>(current).is_some() {
+                break;
+            }
+        }
+    }
+}
@@ -1,15 +1,18 @@
-use bevy::{math::Vec2, ecs::{system::Query, query::{With, Without}, component::Component}, render::{camera::Camera, view::Visibility}, transform::components::GlobalTransform};
+use bevy::{math::Vec2, ecs::{system::{Query, Res}, query::{With, Without}, component::Component}, render::{camera::Camera, view::Visibility}, transform::components::GlobalTransform};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 use bevy::window::{CursorIcon, Window, PrimaryWindow};
 use crate::Transform2D;
 
 use crate::widgets::clipping::CameraClip;
 
-use super::AouiCamera;
+use super::{AouiCamera, InputPlayer};
 
 
 /// Must be unparented to work correctly.
 /// Disabling system cursor is outside the scope of this crate
-#[derive(Debug, Clone, Copy, Component, Default)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct CustomCursor {
     icon: CursorIcon,
     offset: Vec2,
@@ -27,26 +30,34 @@ pub fn custom_cursor_controller(
     windows: Query<&Window, With<PrimaryWindow>>,
     marked_camera: Query<(&Camera, &GlobalTransform), With<AouiCamera>>,
     unmarked_camera: Query<(&Camera, &GlobalTransform), (Without<AouiCamera>, Without<CameraClip>)>,
-    mut query: Query<(&CustomCursor, &mut Transform2D, &mut Visibility)>
+    mut query: Query<(&CustomCursor, &mut Transform2D, &mut Visibility)>,
+    player: Option<Res<InputPlayer>>,
 ) {
-
-    let(camera, camera_transform) = match marked_camera.get_single() {
-        Ok((cam, transform)) => (cam, transform),
-        Err(_) => match unmarked_camera.get_single(){
-            Ok((cam, transform)) => (cam, transform),
-            Err(_) => return,
-        },
+    let window = windows.get_single().ok();
+    let icon = window.map(|window| window.cursor.icon).unwrap_or_default();
+    let mouse_pos = match player.and_then(|x| x.cursor_position()) {
+        // Recorded positions are in world space.
+        Some(position) => Some(position),
+        None => {
+            let(camera, camera_transform) = match marked_camera.get_single() {
+                Ok((cam, transform)) => (cam, transform),
+                Err(_) => match unmarked_camera.get_single(){
+                    Ok((cam, transform)) => (cam, transform),
+                    Err(_) => return,
+                },
+            };
+            let Some(window) = window else { return };
+            window.cursor_position()
+                .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+                .map(|ray| ray.origin.truncate())
+        }
     };
-    let Ok(window) = windows.get_single() else { return };       
-    let mouse_pos = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
-        .map(|ray| ray.origin.truncate());
 
     match mouse_pos {
         Some(pos) => {
             for (cursor, mut transform, mut vis) in query.iter_mut() {
                 if std::mem::discriminant(&cursor.icon) == 
-                        std::mem::discriminant(&window.cursor.icon) {
+                        std::mem::discriminant(&icon) {
                     transform.offset = (pos + cursor.offset).into();
                     *vis = Visibility::Inherited;
                 } else {
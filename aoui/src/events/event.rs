@@ -1,9 +1,15 @@
 use bevy::ecs::component::Component;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::Reflect;
+use bevy::reflect::std_traits::ReflectDefault;
+#[cfg(feature="serde")]
+use bevy::reflect::{ReflectSerialize, ReflectDeserialize};
 
 /// Represents a persistent cursor interaction state like hovering, clicking or dragging.
 /// 
 /// There should be `0` to `1` entity with `CursorFocus` per frame.
-#[derive(Debug, Component, Clone, Copy)]
+#[derive(Debug, Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
 #[component(storage="SparseSet")]
 pub struct CursorFocus(pub(super) EventFlags);
 
@@ -22,7 +28,8 @@ impl CursorFocus {
 /// Represents a single frame cursor event like `LeftClick`.
 /// 
 /// There should be `0` to `1` entity with `CursorAction` per frame.
-#[derive(Debug, Component, Clone, Copy)]
+#[derive(Debug, Component, Clone, Copy, Default, Reflect)]
+#[reflect(Component, Default)]
 #[component(storage="SparseSet")]
 pub struct CursorAction(pub(super) EventFlags);
 
@@ -40,17 +47,25 @@ impl CursorAction {
 
 
 /// Represents cursor clicking outside the sprite's hitbox.
-#[derive(Debug, Component)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 #[component(storage="SparseSet")]
 pub struct CursorClickOutside;
 
+/// Represents an entity losing pointer capture,
+/// see [`CursorState::capture_pointer`](super::CursorState::capture_pointer).
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct PointerCaptureLost;
+
 pub(super) mod sealed {
     use bevy::ecs::component::Component;
 
     tlbf::tlbf!(
         /// Flags for cursor events.
         ///
-        /// Valid listeners are `Hover`, `*Click`, `*Drag`, `DoubleClick`, `Drop` and `ClickOutside`.
+        /// Valid listeners are `Hover`, `*Click`, `*Drag`, `DoubleClick`, `Drop`, `ClickOutside`, `MouseWheel` and `Gesture`.
         ///
         /// * `Hover` listens for `Hover`,
         /// * `Click` listens for `Down`, `Up` and `Pressed`
@@ -58,6 +73,10 @@ pub(super) mod sealed {
         /// * `DoubleClick` listens for `DoubleClick`, which replaces `Click` or `DragEnd`
         /// * `Drop` listens for `Drop`
         /// * `ClickOutside` listens for mouse up outside
+        /// * `MouseWheel` listens for mouse wheel and two finger pan
+        /// * `Gesture` listens for pinch, two finger pan and long press
+        /// * `Other*` listens for extra mouse buttons like back and forward,
+        ///   the button's index can be found in `CursorState::other_button`.
         ///
         /// Events are emitted as 3 separate components, each frame a sprite can receive at most one of each:
         /// * `CursorFocus`: `Hover`, `Pressed`, `Drag`.
@@ -90,9 +109,21 @@ pub(super) mod sealed {
             pub DragEnd,
             pub ClickOutside,
             pub MouseWheel,
+            pub Gesture,
+            // Appended to keep the bits of existing flags stable.
+            pub OtherDown,
+            pub OtherPressed,
+            pub OtherClick,
+            pub OtherDrag,
         }
     );
 
+    impl Default for EventFlags {
+        fn default() -> Self {
+            Self::Idle
+        }
+    }
+
     impl EventFlags {
         pub const fn const_or(self, other: EventFlags) -> Self{
             Self(self.0 | other.0)
@@ -108,13 +139,37 @@ pub(super) mod sealed {
             Self::LeftClick.0 |
             Self::MidClick.0 |
             Self::RightClick.0 |
+            Self::OtherClick.0 |
             Self::Drop.0 |
-            Self::MouseWheel.0
+            Self::MouseWheel.0 |
+            Self::Gesture.0
         );
     }
+
+    #[cfg(feature="serde")]
+    const _: () = {
+        use serde::{Serialize, Deserialize};
+        // Serialized as bits, new flags must be appended.
+        impl Serialize for EventFlags {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
+                self.0.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for EventFlags {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
+                Ok(Self(u32::deserialize(deserializer)?))
+            }
+        }
+    };
 }
 
 pub use sealed::EventFlags;
+
+#[cfg(feature="serde")]
+bevy::reflect::impl_reflect_value!((in bevy_aoui::events) EventFlags(Debug, PartialEq, Serialize, Deserialize, Default));
+#[cfg(not(feature="serde"))]
+bevy::reflect::impl_reflect_value!((in bevy_aoui::events) EventFlags(Debug, PartialEq, Default));
 /// A dummy indicator for no event is happening.
 pub type EvIdle = sealed::Idle;
 /// An event sent if widget is being hovered.
@@ -145,6 +200,22 @@ pub type EvRightPressed = sealed::RightPressed;
 pub type EvRightClick = sealed::RightClick;
 /// An event sent if widget is being dragged by RMB.
 pub type EvRightDrag = sealed::RightDrag;
+/// An event sent if widget is just pressed by an extra mouse button, i.e. back or forward.
+///
+/// Sends the index of `MouseButton::Other`.
+pub type EvOtherDown = sealed::OtherDown;
+/// An event sent if widget is being hovered and pressed by an extra mouse button.
+///
+/// Sends the index of `MouseButton::Other`.
+pub type EvOtherPressed = sealed::OtherPressed;
+/// An event sent if an extra mouse button's down and up both happened inside the widget.
+///
+/// Sends the index of `MouseButton::Other`.
+pub type EvOtherClick = sealed::OtherClick;
+/// An event sent if widget is being dragged by an extra mouse button.
+///
+/// Sends the index of `MouseButton::Other`.
+pub type EvOtherDrag = sealed::OtherDrag;
 /// An event sent if dragging is release inside the widget.
 pub type EvDrop = sealed::Drop;
 /// An event sent if dragging is released.
@@ -153,6 +224,8 @@ pub type EvDragEnd = sealed::DragEnd;
 pub type EvClickOutside = sealed::ClickOutside;
 /// An event sent if mouse wheel was scrolled on the widget.
 pub type EvMouseWheel = sealed::MouseWheel;
+/// An event sent if a multi-touch gesture was performed on the widget.
+pub type EvGesture = sealed::Gesture;
 
 /// An event sent if widget is being dragged.
 /// 
@@ -169,6 +242,51 @@ pub enum EvLoseFocus{}
 #[derive(Debug)]
 pub enum EvObtainFocus{}
 
+/// An event sent if widget has lost pointer capture.
+#[derive(Debug)]
+pub enum EvPointerCaptureLost{}
+
+/// An event sent if widget has obtained keyboard focus.
+#[derive(Debug)]
+pub enum EvObtainKeyboardFocus{}
+
+/// An event sent if widget has lost keyboard focus.
+#[derive(Debug)]
+pub enum EvLoseKeyboardFocus{}
+
+/// An event sent if widget has been pressed for [`HoldThreshold::long_press`](super::HoldThreshold) seconds.
+#[derive(Debug)]
+pub enum EvLongPress{}
+
+/// An event sent repeatedly while a widget is pressed, 
+/// after an initial delay, see [`HoldThreshold`](super::HoldThreshold).
+#[derive(Debug)]
+pub enum EvHoldRepeat{}
+
+/// An event that sends a [`f32`] in `0..=1` every frame while a widget is pressed,
+/// reaching `1` on long press, and `0` when released.
+#[derive(Debug)]
+pub enum EvHoldProgress{}
+
+/// An event sent if a widget with an accepted [`DragPayload`](crate::widgets::dnd::DragPayload)
+/// is dragged over this drop target.
+#[derive(Debug)]
+pub enum EvDragEnter{}
+
+/// An event sent if a dragged widget has left this drop target or has been dropped.
+#[derive(Debug)]
+pub enum EvDragLeave{}
+
+/// An event that sends the source entity and the [`DragPayload`](crate::widgets::dnd::DragPayload)
+/// of a widget dropped on this drop target.
+#[derive(Debug)]
+pub enum EvDropReceived{}
+
+/// An event that sends the [`KeyChord`](super::KeyChord) pressed, 
+/// used by [`Shortcuts`](super::Shortcuts).
+#[derive(Debug)]
+pub enum EvShortcut{}
+
 /// An event that sends the `Payload` value of a widget in the button family.
 #[derive(Debug)]
 pub enum EvButtonClick{}
@@ -190,4 +308,9 @@ pub enum EvTextSubmit{}
 /// An event signal that sends a [`f32`] based on 
 /// the position of a sprite, generated by either scrolling or dragging.
 #[derive(Debug)]
-pub enum EvPositionFactor{}
\ No newline at end of file
+pub enum EvPositionFactor{}
+
+/// An event that sends the index of the page
+/// a [`ScrollSnap`](crate::widgets::scroll::ScrollSnap) is snapping to.
+#[derive(Debug)]
+pub enum EvPageChanged{}
\ No newline at end of file
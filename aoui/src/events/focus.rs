@@ -0,0 +1,241 @@
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res, ResMut, Resource, Commands};
+use bevy::ecs::query::{With, Without, Or, Added};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::hierarchy::{Children, Parent};
+use bevy::input::{Input, keyboard::KeyCode};
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+use bevy::utils::HashMap;
+
+use crate::Transform2D;
+use crate::signals::KeyStorage;
+use crate::widgets::button::{Button, CheckButton, RadioButton};
+
+use super::{ActiveDetection, CursorAction, EventFlags, Handlers, EvObtainKeyboardFocus, EvLoseKeyboardFocus};
+
+/// Marks a widget as able to receive keyboard focus.
+///
+/// Tab traversal visits widgets with a positive `tab_index` first in ascending order,
+/// then widgets with `tab_index` `0` in hierarchy order.
+/// Widgets with a negative `tab_index` are skipped by traversal
+/// but can still be focused by clicking or via [`FocusedEntity::set`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct Focusable {
+    pub tab_index: i32,
+}
+
+impl Focusable {
+    pub const fn new(tab_index: i32) -> Self {
+        Self { tab_index }
+    }
+}
+
+/// Marker component for the entity with keyboard focus.
+///
+/// There should be `0` to `1` entity with `KeyboardFocus`,
+/// this is synchronized with [`FocusedEntity`] during `Update`.
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct KeyboardFocus;
+
+/// The entity with keyboard focus, must be [`Focusable`] and active.
+///
+/// Modify this to change focus programmatically.
+#[derive(Debug, Resource, Default)]
+pub struct FocusedEntity {
+    current: Option<Entity>,
+    dispatched: Option<Entity>,
+}
+
+impl FocusedEntity {
+    /// Obtain the entity with keyboard focus.
+    pub fn get(&self) -> Option<Entity> {
+        self.current
+    }
+
+    /// Returns true if `entity` has keyboard focus.
+    pub fn is(&self, entity: Entity) -> bool {
+        self.current == Some(entity)
+    }
+
+    /// Give keyboard focus to an entity.
+    pub fn set(&mut self, entity: Entity) {
+        self.current = Some(entity)
+    }
+
+    /// Remove keyboard focus.
+    pub fn clear(&mut self) {
+        self.current = None
+    }
+}
+
+/// Spawn order of root widgets, since entity ids are recycled.
+#[derive(Debug, Resource, Default)]
+pub(super) struct RootOrder {
+    next: u64,
+    order: HashMap<Entity, u64>,
+}
+
+impl RootOrder {
+    fn get(&self, entity: Entity) -> u64 {
+        self.order.get(&entity).copied().unwrap_or(u64::MAX)
+    }
+}
+
+/// Record the spawn order of root widgets for tab traversal.
+pub(super) fn track_root_order(
+    mut order: ResMut<RootOrder>,
+    added: Query<Entity, (Added<Transform2D>, Without<Parent>)>,
+    roots: Query<(), (With<Transform2D>, Without<Parent>)>,
+) {
+    if !added.is_empty() {
+        let mut added: Vec<_> = added.iter().collect();
+        // Spawned in the same frame, ids are the best guess.
+        added.sort();
+        for entity in added {
+            let next = order.next;
+            if let bevy::utils::hashbrown::hash_map::Entry::Vacant(entry) = order.order.entry(entity) {
+                entry.insert(next);
+                order.next += 1;
+            }
+        }
+    }
+    if order.order.len() > roots.iter().len() {
+        order.order.retain(|entity, _| roots.contains(*entity));
+    }
+}
+
+/// Collect active focusable entities in tab order.
+///
+/// Roots are visited in spawn order, descendants in hierarchy order.
+pub(super) fn tab_order(
+    roots: &Query<Entity, (With<Transform2D>, Without<Parent>)>,
+    root_order: &RootOrder,
+    hierarchy: &Query<&Children>,
+    focusables: &Query<(&Focusable, ActiveDetection)>,
+) -> Vec<Entity> {
+    fn walk(
+        entity: Entity,
+        hierarchy: &Query<&Children>,
+        focusables: &Query<(&Focusable, ActiveDetection)>,
+        out: &mut Vec<(i32, Entity)>,
+    ) {
+        if let Ok((focusable, active)) = focusables.get(entity) {
+            if focusable.tab_index >= 0 && active.is_active() {
+                out.push((focusable.tab_index, entity));
+            }
+        }
+        if let Ok(children) = hierarchy.get(entity) {
+            for child in children.iter() {
+                walk(*child, hierarchy, focusables, out);
+            }
+        }
+    }
+
+    let mut roots: Vec<_> = roots.iter().collect();
+    roots.sort_by_key(|entity| (root_order.get(*entity), *entity));
+    let mut order = Vec::new();
+    for root in roots {
+        walk(root, hierarchy, focusables, &mut order);
+    }
+    // Stable, positive indices first, then `0` in hierarchy order.
+    order.sort_by_key(|(index, _)| (*index == 0, *index));
+    order.into_iter().map(|(_, entity)| entity).collect()
+}
+
+/// Move keyboard focus with `Tab` and `Shift+Tab`.
+pub fn tab_navigation(
+    keys: Res<Input<KeyCode>>,
+    mut focused: ResMut<FocusedEntity>,
+    roots: Query<Entity, (With<Transform2D>, Without<Parent>)>,
+    root_order: Res<RootOrder>,
+    hierarchy: Query<&Children>,
+    focusables: Query<(&Focusable, ActiveDetection)>,
+) {
+    if !keys.just_pressed(KeyCode::Tab) { return; }
+    let order = tab_order(&roots, &root_order, &hierarchy, &focusables);
+    if order.is_empty() { return; }
+    let reverse = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let position = focused.get().and_then(|entity| order.iter().position(|x| *x == entity));
+    let next = match (position, reverse) {
+        (Some(idx), false) => (idx + 1) % order.len(),
+        (Some(idx), true) => (idx + order.len() - 1) % order.len(),
+        (None, false) => 0,
+        (None, true) => order.len() - 1,
+    };
+    focused.set(order[next]);
+}
+
+/// Widgets that can be activated without a cursor.
+pub(super) type Activatable<'w, 's> = Query<'w, 's, ActiveDetection, (
+    Without<CursorAction>,
+    Or<(With<Button>, With<CheckButton>, With<RadioButton>)>
+)>;
+
+/// Send a `LeftClick` to an active [`Button`], [`CheckButton`] or [`RadioButton`].
+pub(super) fn activate(commands: &mut Commands, query: &Activatable, entity: Entity) {
+    if query.get(entity).map(|x| x.is_active()).unwrap_or(false) {
+        commands.entity(entity).insert(CursorAction(EventFlags::LeftClick));
+    }
+}
+
+/// Activate the focused [`Button`], [`CheckButton`] or [`RadioButton`]
+/// with `Enter` or `Space`, this sends a `LeftClick` to the widget.
+pub fn keyboard_activation(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    focused: Res<FocusedEntity>,
+    query: Activatable,
+) {
+    if !keys.any_just_pressed([KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Space]) { return; }
+    let Some(entity) = focused.get() else { return };
+    activate(&mut commands, &query, entity);
+}
+
+/// Give keyboard focus to a [`Focusable`] widget pressed by the cursor.
+pub fn focus_on_click(
+    mut focused: ResMut<FocusedEntity>,
+    query: Query<(Entity, &CursorAction), With<Focusable>>,
+) {
+    for (entity, action) in query.iter() {
+        if action.intersects(EventFlags::LeftDown | EventFlags::MidDown | EventFlags::RightDown | EventFlags::OtherDown) {
+            focused.set(entity);
+        }
+    }
+}
+
+/// Synchronize [`KeyboardFocus`] with [`FocusedEntity`],
+/// and send [`EvObtainKeyboardFocus`] and [`EvLoseKeyboardFocus`].
+pub fn keyboard_focus_detection(
+    mut commands: Commands,
+    keys: Res<KeyStorage>,
+    mut focused: ResMut<FocusedEntity>,
+    active: Query<ActiveDetection, With<Focusable>>,
+    obtain: Query<&Handlers<EvObtainKeyboardFocus>>,
+    lose: Query<&Handlers<EvLoseKeyboardFocus>>,
+) {
+    if let Some(entity) = focused.current {
+        if !active.get(entity).map(|x| x.is_active()).unwrap_or(false) {
+            focused.current = None;
+        }
+    }
+    if focused.current == focused.dispatched { return; }
+    if let Some(entity) = focused.dispatched {
+        if let Some(mut commands) = commands.get_entity(entity) {
+            commands.remove::<KeyboardFocus>();
+            if let Ok(handlers) = lose.get(entity) {
+                handlers.handle(&mut commands, &keys, ());
+            }
+        }
+    }
+    if let Some(entity) = focused.current {
+        let mut commands = commands.entity(entity);
+        commands.insert(KeyboardFocus);
+        if let Ok(handlers) = obtain.get(entity) {
+            handlers.handle(&mut commands, &keys, ());
+        }
+    }
+    focused.dispatched = focused.current;
+}
@@ -3,18 +3,21 @@ use std::fmt::Debug;
 use bevy::ecs::{component::Component, removal_detection::RemovedComponents};
 use bevy::ecs::query::{Without, With};
 use bevy::ecs::system::{Query, Commands, EntityCommands};
+use bevy::input::mouse::MouseButton;
 use smallvec::SmallVec;
 
 use crate::dsl::{DslFrom, DslInto};
 use crate::events::*;
 use crate::signals::{SignalSender, SignalMapper, SignalBuilder, KeyStorage, Object, AsObject};
 use crate::widgets::drag::DragState;
+use crate::widgets::dnd::DropReceived;
 
 use self::sealed::EventQuery;
 
 use super::mutation::{Mutation, IntoMutationCommand};
 use super::oneshot::OneShot;
-use super::{EvLoseFocus, EvObtainFocus, EvButtonClick, EvTextSubmit, EvTextChange, EvToggleChange, EvMouseDrag, EvPositionFactor};
+use super::registry::run_named_handler;
+use super::{EvLoseFocus, EvObtainFocus, EvObtainKeyboardFocus, EvLoseKeyboardFocus, EvLongPress, EvHoldRepeat, EvHoldProgress, EvDragEnter, EvDragLeave, EvDropReceived, EvShortcut, KeyChord, EvButtonClick, EvTextSubmit, EvTextChange, EvToggleChange, EvMouseDrag, EvPositionFactor, EvPageChanged};
 
 /// Event handlers.
 #[derive(Debug, Component)]
@@ -42,6 +45,15 @@ pub enum Handler<T: EventHandling> {
     Signal(SignalSender<T::Data>),
     /// Set a key-value pair in a [storage](crate::signals::KeyStorage).
     GlobalKey(String, SignalMapper),
+    /// Run a handler registered in [`HandlerRegistry`] by name.
+    Named(String),
+}
+
+impl<T: EventHandling> Handler<T> {
+    /// Reference a handler registered in [`HandlerRegistry`] by name.
+    pub fn named(name: impl Into<String>) -> Self {
+        Handler::Named(name.into())
+    }
 }
 
 impl<T: EventHandling> DslFrom<OneShot> for Handler<T> {
@@ -184,6 +196,10 @@ impl<T: EventHandling> Handlers<T> {
                 Handler::GlobalKey(name, mapper) => {
                     keys.set_dyn(name, mapper.map(Object::new(data.clone())));
                 },
+                Handler::Named(name) => {
+                    let entity = commands.id();
+                    run_named_handler(commands.commands(), entity, name.clone(), Object::new(data.clone()));
+                },
             }
         }
     }
@@ -207,6 +223,10 @@ impl<T: EventHandling> Handlers<T> {
                 Handler::GlobalKey(name, mapper) => {
                     keys.set_dyn(name, mapper.map(data.clone()));
                 },
+                Handler::Named(name) => {
+                    let entity = commands.id();
+                    run_named_handler(commands.commands(), entity, name.clone(), data.clone());
+                },
             }
         }
     }
@@ -217,6 +237,7 @@ impl<T: EventHandling> Handlers<T> {
             Handler::Mutation(_) => (),
             Handler::Signal(sig) => sig.try_cleanup(drop_flag),
             Handler::GlobalKey(_, _) => (),
+            Handler::Named(_) => (),
         })
     }
 }
@@ -242,6 +263,45 @@ pub fn handle_event<T: EventQuery + Send + Sync + 'static> (
     }
 }
 
+/// Run handlers of `Other*` events, sending the index of `MouseButton::Other`.
+pub fn handle_other_button(
+    mut commands: Commands,
+    keys: Res<KeyStorage>,
+    state: Res<CursorState>,
+    down: Query<(Entity, &CursorAction, &Handlers<EvOtherDown>)>,
+    click: Query<(Entity, &CursorAction, &Handlers<EvOtherClick>)>,
+    pressed: Query<(Entity, &CursorFocus, &Handlers<EvOtherPressed>)>,
+    drag: Query<(Entity, &CursorFocus, &Handlers<EvOtherDrag>)>,
+) {
+    let button = state.other_button();
+    // Pressed and dragged widgets follow the held button,
+    // not the last pressed one.
+    let held = match (state.dragging, state.drag_button) {
+        (true, MouseButton::Other(index)) => index,
+        _ => button,
+    };
+    for (entity, action, system) in down.iter() {
+        if action.is(EventFlags::OtherDown) {
+            system.handle(&mut commands.entity(entity), &keys, button);
+        }
+    }
+    for (entity, action, system) in click.iter() {
+        if action.is(EventFlags::OtherClick) {
+            system.handle(&mut commands.entity(entity), &keys, button);
+        }
+    }
+    for (entity, focus, system) in pressed.iter() {
+        if focus.is(EventFlags::OtherPressed) {
+            system.handle(&mut commands.entity(entity), &keys, held);
+        }
+    }
+    for (entity, focus, system) in drag.iter() {
+        if focus.is(EventFlags::OtherDrag) {
+            system.handle(&mut commands.entity(entity), &keys, held);
+        }
+    }
+}
+
 mod sealed {
     use bevy::ecs::component::Component;
     use super::{EventHandling, CursorAction, EventFlags};
@@ -285,6 +345,23 @@ impl EventHandling for EvMouseWheel {
     fn new_context() -> Self::Context {}
 }
 
+impl EventHandling for EvGesture {
+    type Data = GestureAction;
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventQuery for EvGesture {
+    type Component = GestureAction;
+
+    fn validate(_: &Self::Context, _: &Self::Component) -> bool {
+        true
+    }
+    fn get_data(_: &Self::Context, action: &Self::Component) -> Self::Data {
+        *action
+    }
+}
+
 impl EventHandling for EvMouseDrag {
     type Data = DragState;
     type Context = DragState;
@@ -308,6 +385,21 @@ impl EventQuery for EvClickOutside {
     fn get_data(_: &Self::Context, _: &Self::Component) -> Self::Data {}
 }
 
+impl EventHandling for EvPointerCaptureLost {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventQuery for EvPointerCaptureLost {
+    type Component = PointerCaptureLost;
+
+    fn validate(_: &Self::Context, _: &Self::Component) -> bool {
+        true
+    }
+    fn get_data(_: &Self::Context, _: &Self::Component) -> Self::Data {}
+}
+
 macro_rules! impl_entity_query_for_mouse_state {
     ($($ident:ident)*) => {
         $(impl EventHandling for $crate::events::sealed::$ident {
@@ -331,6 +423,20 @@ impl_entity_query_for_mouse_state! (
     LeftDrag MidDrag RightDrag
 );
 
+macro_rules! impl_event_handling_for_other_button {
+    ($($ident:ident)*) => {
+        $(impl EventHandling for $crate::events::sealed::$ident {
+            type Data = u16;
+            type Context = ();
+            fn new_context() -> Self::Context {}
+        })*
+    };
+}
+
+impl_event_handling_for_other_button!(
+    OtherDown OtherPressed OtherClick OtherDrag
+);
+
 impl EventHandling for EvLoseFocus {
     type Data = ();
     type Context = ();
@@ -344,6 +450,60 @@ impl EventHandling for EvObtainFocus {
     fn new_context() -> Self::Context { false }
 }
 
+impl EventHandling for EvObtainKeyboardFocus {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvLoseKeyboardFocus {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvLongPress {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvHoldRepeat {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvHoldProgress {
+    type Data = f32;
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvDragEnter {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvDragLeave {
+    type Data = ();
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvDropReceived {
+    type Data = DropReceived;
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
+impl EventHandling for EvShortcut {
+    type Data = KeyChord;
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
 impl EventHandling for EvButtonClick {
     type Data = Object;
     type Context = ();
@@ -374,6 +534,12 @@ impl EventHandling for EvPositionFactor {
     fn new_context() -> Self::Context {}
 }
 
+impl EventHandling for EvPageChanged {
+    type Data = usize;
+    type Context = ();
+    fn new_context() -> Self::Context {}
+}
+
 pub fn obtain_focus_detection(
     mut commands: Commands,
     keys: Res<KeyStorage>,
@@ -0,0 +1,150 @@
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res, Resource, Commands};
+use bevy::ecs::query::{With, Or};
+use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+use bevy::time::Time;
+
+use crate::signals::KeyStorage;
+
+use super::{CursorFocus, EventFlags, Handlers, EvLongPress, EvHoldRepeat, EvHoldProgress};
+
+/// Timing of long presses and hold-to-repeat.
+///
+/// As a resource this sets the default of all widgets,
+/// as a component this overrides the default for a single widget.
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Component, Reflect)]
+#[reflect(Component, Resource, Default)]
+pub struct HoldThreshold {
+    /// Time in seconds before `EvLongPress` is sent.
+    pub long_press: f32,
+    /// Time in seconds before the first `EvHoldRepeat` is sent.
+    pub repeat_delay: f32,
+    /// Time in seconds between subsequent `EvHoldRepeat`s.
+    pub repeat_interval: f32,
+}
+
+impl Default for HoldThreshold {
+    fn default() -> Self {
+        Self {
+            long_press: 0.5,
+            repeat_delay: 0.4,
+            repeat_interval: 0.08,
+        }
+    }
+}
+
+impl HoldThreshold {
+    pub fn new(long_press: f32, repeat_delay: f32, repeat_interval: f32) -> Self {
+        Self { long_press, repeat_delay, repeat_interval }
+    }
+}
+
+/// State of a widget being pressed, removed when released.
+///
+/// `progress` goes from `0` to `1` in [`HoldThreshold::long_press`] seconds,
+/// useful for "hold to confirm" widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct HoldProgress {
+    elapsed: f32,
+    progress: f32,
+    repeats: u32,
+}
+
+impl HoldProgress {
+    /// Time in seconds since the widget is pressed.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Progress towards a long press in `0..=1`.
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Number of `EvHoldRepeat`s sent.
+    pub fn repeats(&self) -> u32 {
+        self.repeats
+    }
+}
+
+/// Pressed or dragged, since a held widget receives `*Drag` instead of `*Pressed` if it supports dragging.
+const PRESSED: EventFlags = EventFlags::LeftPressed
+    .const_or(EventFlags::MidPressed)
+    .const_or(EventFlags::RightPressed)
+    .const_or(EventFlags::OtherPressed)
+    .const_or(EventFlags::LeftDrag)
+    .const_or(EventFlags::MidDrag)
+    .const_or(EventFlags::RightDrag)
+    .const_or(EventFlags::OtherDrag);
+
+/// Track [`HoldProgress`] of pressed widgets,
+/// send [`EvLongPress`], [`EvHoldRepeat`] and [`EvHoldProgress`].
+pub fn hold_detection(
+    mut commands: Commands,
+    time: Res<Time>,
+    keys: Res<KeyStorage>,
+    default: Res<HoldThreshold>,
+    mut query: Query<(
+        Entity,
+        Option<&CursorFocus>,
+        Option<&HoldThreshold>,
+        Option<&mut HoldProgress>,
+        Option<&Handlers<EvLongPress>>,
+        Option<&Handlers<EvHoldRepeat>>,
+        Option<&Handlers<EvHoldProgress>>,
+    ), Or<(With<CursorFocus>, With<HoldProgress>)>>,
+) {
+    for (entity, focus, threshold, hold, long_press, repeat, progress) in query.iter_mut() {
+        let pressed = focus.map(|x| x.intersects(PRESSED)).unwrap_or(false);
+        let mut commands = commands.entity(entity);
+        let Some(mut hold) = hold else {
+            if pressed {
+                commands.insert(HoldProgress::default());
+                if let Some(handlers) = progress {
+                    handlers.handle(&mut commands, &keys, 0.0);
+                }
+            }
+            continue;
+        };
+        if !pressed {
+            commands.remove::<HoldProgress>();
+            if let Some(handlers) = progress {
+                handlers.handle(&mut commands, &keys, 0.0);
+            }
+            continue;
+        }
+        let threshold = threshold.unwrap_or(&default);
+        let last = hold.elapsed;
+        hold.elapsed += time.delta_seconds();
+        hold.progress = if threshold.long_press > 0.0 {
+            (hold.elapsed / threshold.long_press).min(1.0)
+        } else {
+            1.0
+        };
+        if last < threshold.long_press && hold.elapsed >= threshold.long_press {
+            if let Some(handlers) = long_press {
+                handlers.handle(&mut commands, &keys, ());
+            }
+        }
+        if hold.elapsed >= threshold.repeat_delay {
+            // At most one repeat per frame.
+            let count = if threshold.repeat_interval > 0.0 {
+                ((hold.elapsed - threshold.repeat_delay) / threshold.repeat_interval) as u32 + 1
+            } else {
+                hold.repeats + 1
+            };
+            if count > hold.repeats {
+                hold.repeats += 1;
+                if let Some(handlers) = repeat {
+                    handlers.handle(&mut commands, &keys, ());
+                }
+            }
+        }
+        if let Some(handlers) = progress {
+            handlers.handle(&mut commands, &keys, hold.progress);
+        }
+    }
+}
@@ -6,7 +6,7 @@
 //! listen to a subset of events.
 //! 
 //! Only a subset of EventFlags are valid event listeners,
-//! `*` is Left, Mid or Center, other mouse buttons are ignored.
+//! `*` is Left, Mid, Right or Other.
 //! 
 //! * `Hover` listens for `Hover`,
 //! * `*Click` listens for `*Down`, `*Up` and `*Pressed`
@@ -15,10 +15,22 @@
 //! * `Drop` listens for `Drop`
 //! * `ClickOutside` listens for mouse up outside of the sprite's boundary.
 //! 
+//! `Other` covers extra mouse buttons like back and forward,
+//! handlers of `EvOther*` receive the index of `MouseButton::Other`,
+//! also available in [`CursorState::other_button`].
+//! 
+//! A [`DragThreshold`] delays `*Drag` until the cursor has moved or been held long enough,
+//! the widget receives `*Pressed` until then and `*Click` if released.
+//! 
+//! `Left` and `Other` drags hold the drag target,
+//! so they keep receiving `*Drag` outside of the hitbox
+//! and [`CursorState::dragging`] is true during them.
+//! `Right` and `Mid` drags begin immediately and do not hold the drag target.
+//! 
 //! # Event Propagation
 //! 
 //! We use component insertion to send events to widgets. These are
-//! `CursorFocus`, `CursorAction`, `CursorClickOutside` and `MouseWheelAction`.
+//! `CursorFocus`, `CursorAction`, `CursorClickOutside`, `MouseWheelAction` and `GestureAction`.
 //! You can use these with queries.
 //! 
 //! They should be safe to use during `Update` and `PostUpdate`.
@@ -30,6 +42,24 @@
 //! * `CursorAction`: Stores a single frame event like `Click` or `Down`.
 //! * `CursorClickOutside`: Mouse up outside of the sprite's boundary.
 //! * `MouseWheelAction`: Stores the value of mouse wheel scrolling.
+//! * `GestureAction`: Stores multi-touch gestures like pinch and two finger pan.
+//! 
+//! # Pointer Capture
+//! 
+//! Call [`CursorState::capture_pointer`] to route cursor events to an entity regardless of hit testing,
+//! useful for knobs and sliders that continue outside of their bounds.
+//! The entity receives `Hover`, `*Down`, `*Pressed` and `MouseWheelAction`,
+//! `*Drag` once a held button exceeds the [`DragThreshold`], then `DragEnd` on release,
+//! or `*Click` if released under the threshold inside its hitbox,
+//! until [`CursorState::release_pointer`] is called or all mouse buttons are released,
+//! then receives `PointerCaptureLost`.
+//! 
+//! # Event Bubbling
+//! 
+//! Add [`EventBubbling`] to an entity to receive `CursorAction`s from its descendants.
+//! Bubbled actions run the entity's `Handlers` during `PostUpdate`,
+//! with the origin available in [`BubbledAction`].
+//! Insert [`EventConsumed`] in a handler to stop bubbling.
 //! 
 //! # Event Handlers
 //! 
@@ -50,13 +80,66 @@
 //! * Send a signal.
 //! * Write signal input to a [`KeyStorage`](crate::signals::KeyStorage).
 //! 
-//! # What about Keyboard Events or Joysticks?
+//! # Touch
+//! 
+//! A single finger acts as the left mouse button, so taps are clicks and
+//! touch drags are drags. Two finger gestures are sent as `GestureAction`,
+//! two finger pans also scroll `MouseWheel` listeners.
+//! Holding a finger on a widget sends `EvLongPress`, see [Hold](#hold).
+//! 
+//! # Hold
+//! 
+//! Pressed widgets receive [`HoldProgress`], removed when released or
+//! when the cursor leaves the widget.
+//! 
+//! * `EvLongPress` is sent once after [`HoldThreshold::long_press`] seconds.
+//! * `EvHoldRepeat` is sent every [`HoldThreshold::repeat_interval`] seconds
+//!   after [`HoldThreshold::repeat_delay`] seconds.
+//! * `EvHoldProgress` sends progress towards a long press every frame.
+//! 
+//! Add [`HoldThreshold`] as a component to override the global resource.
+//! 
+//! # Recording
+//! 
+//! Insert [`InputRecorder`] to record the cursor position, mouse buttons, mouse wheel,
+//! keys and received characters every frame into an [`InputRecording`].
+//! Insert [`InputPlayer`] to replay a recording, this works in a headless app
+//! since recorded cursor positions are in world space.
+//! 
+//! # Keyboard Focus
+//! 
+//! Add [`Focusable`] to a widget to allow it to receive keyboard focus,
+//! the focused entity is stored in the [`FocusedEntity`] resource
+//! and marked with [`KeyboardFocus`].
+//! 
+//! * `Tab` and `Shift+Tab` move focus in tab order.
+//! * Pressing a `Focusable` widget gives it focus.
+//! * `Enter` or `Space` clicks the focused `Button`, `CheckButton` or `RadioButton`.
+//! * `EvObtainKeyboardFocus` and `EvLoseKeyboardFocus` are sent when focus changes.
+//! 
+//! # Spatial Navigation
+//! 
+//! Arrow keys and the gamepad d-pad move focus to the nearest `Focusable` widget
+//! in that direction, the gamepad's south button activates the focused widget.
+//! 
+//! * [`NavigationMapping`] configures bindings, remove it to disable spatial navigation.
+//! * [`NavigationOverride`] specifies explicit targets per widget.
+//! * [`NavigationScope`] keeps focus inside its descendants, i.e. a modal.
+//! 
+//! # Shortcuts
+//! 
+//! [`Shortcuts`] maps [`KeyChord`]s like `Ctrl+S` to `Handlers<EvShortcut>`.
 //! 
-//! We provide abstractions that you can use for other types of input, 
-//! but these are outside the scope of this crate.
+//! * [`ShortcutScope::Global`] shortcuts are active unless focus is inside a
+//!   `NavigationScope` that does not contain them.
+//! * [`ShortcutScope::Focused`] shortcuts are active if focus is on the entity or its descendants.
+//! * If multiple entities bind the same chord, the deepest in the hierarchy wins.
+//! * Shortcuts run before keyboard navigation and consume their key,
+//!   so binding `Tab`, `Enter` or arrow keys overrides navigation.
+//! * [`ShortcutLabel`] displays a chord in a text widget, i.e. a menu item.
 
 use bevy::{prelude::*, ecs::query::WorldQuery};
-use crate::{schedule::{AouiEventSet, AouiCleanupSet}, Hitbox, Clipping, RotatedRect, Opacity, widgets::button::CursorDefault};
+use crate::{schedule::{AouiEventSet, AouiCleanupSet, AouiLoadInputSet}, Hitbox, Clipping, RotatedRect, Opacity, widgets::button::CursorDefault};
 
 mod systems;
 mod state;
@@ -68,6 +151,15 @@ pub(crate) mod mutation;
 mod oneshot;
 mod coverage;
 mod fetch;
+mod picking;
+mod registry;
+mod focus;
+mod navigation;
+mod touch;
+mod bubble;
+mod hold;
+mod shortcut;
+mod record;
 
 pub use event::*;
 pub use state::*;
@@ -78,6 +170,15 @@ pub use cursor::CustomCursor;
 pub use mutation::Mutation;
 pub use oneshot::OneShot;
 pub use fetch::*;
+pub use picking::AouiPicking;
+pub use registry::{HandlerRegistry, NamedHandler};
+pub use focus::{Focusable, FocusedEntity, KeyboardFocus};
+pub use bubble::{EventBubbling, EventConsumed, BubbledAction};
+pub use hold::{HoldThreshold, HoldProgress};
+pub use shortcut::{KeyChord, Shortcuts, ShortcutScope, ShortcutLabel};
+pub use record::{InputFrame, InputRecording, InputRecorder, InputPlayer};
+pub use touch::{TouchState, GestureAction};
+pub use navigation::{NavigationInput, NavigationMapping, NavigationOverride, NavigationScope};
 
 use self::cursor::custom_cursor_controller;
 pub use coverage::{FetchCoveragePercent, FetchCoveragePx};
@@ -86,7 +187,8 @@ pub use coverage::{FetchCoveragePercent, FetchCoveragePx};
 /// 
 /// Used for cursor detection and has no effect on rendering.
 /// If not present, we will try the `.get_single()` method instead.
-#[derive(Debug, Clone, Copy, Component, Default)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct AouiCamera;
 
 
@@ -119,6 +221,8 @@ impl CursorDetectionItem<'_> {
             && self.clipping.contains(pos)
     }
 
+    /// Compare depth, this is consistent with rendering order
+    /// since both are derived from [`RotatedRect::z`].
     pub fn compare(&self, other: &Self) -> std::cmp::Ordering {
         self.rect.z.total_cmp(&other.rect.z)
     }
@@ -137,10 +241,64 @@ impl bevy::prelude::Plugin for CursorEventsPlugin {
         app.init_resource::<CursorState>()
             .init_resource::<ScrollScaling>()
             .init_resource::<DoubleClickThreshold>()
+            .init_resource::<DragThreshold>()
             .init_resource::<CursorDefault>()
-            .add_systems(PreUpdate, mouse_button_input.in_set(AouiEventSet))
+            .init_resource::<HandlerRegistry>()
+            .init_resource::<FocusedEntity>()
+            .init_resource::<focus::RootOrder>()
+            .init_resource::<NavigationMapping>()
+            .init_resource::<TouchState>()
+            .init_resource::<HoldThreshold>()
+            .register_type::<EventFlags>()
+            .register_type::<CursorFocus>()
+            .register_type::<CursorAction>()
+            .register_type::<CursorClickOutside>()
+            .register_type::<PointerCaptureLost>()
+            .register_type::<MouseWheelAction>()
+            .register_type::<GestureAction>()
+            .register_type::<EventBubbling>()
+            .register_type::<EventConsumed>()
+            .register_type::<BubbledAction>()
+            .register_type::<CustomCursor>()
+            .register_type::<AouiCamera>()
+            .register_type::<Focusable>()
+            .register_type::<KeyboardFocus>()
+            .register_type::<NavigationInput>()
+            .register_type::<NavigationOverride>()
+            .register_type::<NavigationScope>()
+            .register_type::<HoldThreshold>()
+            .register_type::<DragThreshold>()
+            .register_type::<KeyChord>()
+            .register_type::<ShortcutScope>()
+            .register_type::<ShortcutLabel>()
+            .register_type::<HoldProgress>()
+            .add_systems(First, record::playback_time.before(bevy::time::TimeSystem))
+            .add_systems(PreUpdate, (
+                record::input_playback,
+                record::input_recording,
+            ).chain().after(bevy::input::InputSystem).before(AouiEventSet))
+            .add_systems(PreUpdate, (
+                (
+                    touch::touch_input,
+                    mouse_button_input,
+                    pointer_capture_lost,
+                ).chain(),
+                // Keyboard activation skips widgets that received a `CursorAction` this frame.
+                apply_deferred,
+                (
+                    focus::track_root_order,
+                    shortcut::shortcut_input,
+                    focus::tab_navigation,
+                    navigation::spatial_navigation,
+                    focus::keyboard_activation,
+                ).chain(),
+            ).chain().in_set(AouiEventSet))
             .add_systems(PreUpdate, wheel::mousewheel_event.in_set(AouiEventSet))
+            .add_systems(PreUpdate, touch::touch_gesture_event
+                .after(touch::touch_input)
+                .in_set(AouiEventSet))
             .add_systems(Last, remove_focus.in_set(AouiCleanupSet))
+            .add_systems(PostUpdate, bubble::bubble_cursor_action.before(AouiLoadInputSet))
             .add_systems(Update, (
                 handle_event::<EvLeftClick>,
                 handle_event::<EvLeftDown>,
@@ -159,7 +317,10 @@ impl bevy::prelude::Plugin for CursorEventsPlugin {
                 handle_event::<EvMidDrag>,
                 handle_event::<EvRightPressed>,
                 handle_event::<EvRightDrag>,
+                handle_event::<EvGesture>,
+                handle_event::<EvPointerCaptureLost>,
             ))
+            .add_systems(Update, handle_other_button)
             .add_systems(Update, (
                 fetch::transfer_offset,
                 fetch::transfer_offset_evaluated,
@@ -176,8 +337,11 @@ impl bevy::prelude::Plugin for CursorEventsPlugin {
             .add_systems(Update, (
                 lose_focus_detection,
                 obtain_focus_detection,
+                (focus::focus_on_click, focus::keyboard_focus_detection).chain(),
                 custom_cursor_controller,
                 coverage::calculate_coverage,
+                hold::hold_detection,
+                shortcut::shortcut_label,
             ))
         ;
     }
@@ -0,0 +1,222 @@
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res, ResMut, Resource, Commands};
+use bevy::ecs::query::{With, Without};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::hierarchy::{Children, Parent};
+use bevy::input::{Input, keyboard::KeyCode, gamepad::{GamepadButton, GamepadButtonType}};
+use bevy::math::Vec2;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+
+use crate::{RotatedRect, Transform2D};
+use crate::widgets::inputbox::InputBox;
+
+use super::{ActiveDetection, Focusable, FocusedEntity};
+use super::focus::{Activatable, RootOrder, activate, tab_order};
+
+/// A direction or action of spatial navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum NavigationInput {
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Clicks the focused `Button`, `CheckButton` or `RadioButton`.
+    Activate,
+}
+
+impl NavigationInput {
+    fn direction(&self) -> Option<Vec2> {
+        match self {
+            NavigationInput::Up => Some(Vec2::Y),
+            NavigationInput::Down => Some(Vec2::NEG_Y),
+            NavigationInput::Left => Some(Vec2::NEG_X),
+            NavigationInput::Right => Some(Vec2::X),
+            NavigationInput::Activate => None,
+        }
+    }
+}
+
+/// Maps keys and gamepad buttons to [`NavigationInput`]s.
+///
+/// By default arrow keys and the d-pad navigate and
+/// the gamepad's south button activates.
+/// Remove this resource to disable spatial navigation.
+#[derive(Debug, Clone, Resource)]
+pub struct NavigationMapping {
+    pub keys: Vec<(KeyCode, NavigationInput)>,
+    pub gamepad: Vec<(GamepadButtonType, NavigationInput)>,
+}
+
+impl Default for NavigationMapping {
+    fn default() -> Self {
+        Self {
+            keys: vec![
+                (KeyCode::Up, NavigationInput::Up),
+                (KeyCode::Down, NavigationInput::Down),
+                (KeyCode::Left, NavigationInput::Left),
+                (KeyCode::Right, NavigationInput::Right),
+            ],
+            gamepad: vec![
+                (GamepadButtonType::DPadUp, NavigationInput::Up),
+                (GamepadButtonType::DPadDown, NavigationInput::Down),
+                (GamepadButtonType::DPadLeft, NavigationInput::Left),
+                (GamepadButtonType::DPadRight, NavigationInput::Right),
+                (GamepadButtonType::South, NavigationInput::Activate),
+            ],
+        }
+    }
+}
+
+impl NavigationMapping {
+    /// Mapping with no bindings.
+    pub fn empty() -> Self {
+        Self { keys: Vec::new(), gamepad: Vec::new() }
+    }
+
+    /// Bind a key to an input.
+    pub fn with_key(mut self, key: KeyCode, input: NavigationInput) -> Self {
+        self.keys.push((key, input));
+        self
+    }
+
+    /// Bind a gamepad button on any gamepad to an input.
+    pub fn with_gamepad(mut self, button: GamepadButtonType, input: NavigationInput) -> Self {
+        self.gamepad.push((button, input));
+        self
+    }
+
+    fn just_pressed(&self, keys: &Input<KeyCode>, buttons: &Input<GamepadButton>) -> Option<NavigationInput> {
+        self.keys.iter()
+            .find(|(key, _)| keys.just_pressed(*key))
+            .map(|(_, input)| *input)
+            .or_else(|| self.gamepad.iter()
+                .find(|(ty, _)| buttons.get_just_pressed().any(|x| &x.button_type == ty))
+                .map(|(_, input)| *input))
+    }
+}
+
+/// Explicit navigation targets of a [`Focusable`] widget,
+/// takes priority over spatial navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct NavigationOverride {
+    pub up: Option<Entity>,
+    pub down: Option<Entity>,
+    pub left: Option<Entity>,
+    pub right: Option<Entity>,
+}
+
+impl NavigationOverride {
+    fn get(&self, input: NavigationInput) -> Option<Entity> {
+        match input {
+            NavigationInput::Up => self.up,
+            NavigationInput::Down => self.down,
+            NavigationInput::Left => self.left,
+            NavigationInput::Right => self.right,
+            NavigationInput::Activate => None,
+        }
+    }
+}
+
+/// Restricts spatial navigation to descendants of this entity
+/// while one of them has focus, i.e. a modal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct NavigationScope;
+
+/// Find the closest [`NavigationScope`] ancestor, including self.
+pub(super) fn find_scope(entity: Entity, parents: &Query<&Parent>, scopes: &Query<(), With<NavigationScope>>) -> Option<Entity> {
+    let mut current = entity;
+    loop {
+        if scopes.contains(current) {
+            return Some(current);
+        }
+        current = parents.get(current).ok()?.get();
+    }
+}
+
+pub(super) fn is_descendant(entity: Entity, ancestor: Entity, parents: &Query<&Parent>) -> bool {
+    let mut current = entity;
+    while let Ok(parent) = parents.get(current) {
+        current = parent.get();
+        if current == ancestor {
+            return true;
+        }
+    }
+    false
+}
+
+/// Move keyboard focus to the nearest [`Focusable`] widget in a direction
+/// based on [`NavigationMapping`], or activate the focused widget.
+pub fn spatial_navigation(
+    mut commands: Commands,
+    mapping: Option<Res<NavigationMapping>>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    mut focused: ResMut<FocusedEntity>,
+    roots: Query<Entity, (With<Transform2D>, Without<Parent>)>,
+    root_order: Res<RootOrder>,
+    hierarchy: Query<&Children>,
+    parents: Query<&Parent>,
+    scopes: Query<(), With<NavigationScope>>,
+    focusables: Query<(&Focusable, ActiveDetection)>,
+    rects: Query<(Entity, &RotatedRect, Option<&NavigationOverride>, ActiveDetection), With<Focusable>>,
+    text_inputs: Query<(), With<InputBox>>,
+    activatable: Activatable,
+) {
+    let Some(mapping) = mapping else { return };
+    let Some(input) = mapping.just_pressed(&keys, &buttons) else { return };
+    let Some(direction) = input.direction() else {
+        if let Some(entity) = focused.get() {
+            activate(&mut commands, &activatable, entity);
+        }
+        return;
+    };
+    let current = focused.get().and_then(|entity| match rects.get(entity) {
+        Ok((entity, rect, nav, active)) if active.is_active() => Some((entity, rect, nav)),
+        _ => None,
+    });
+    let Some((current, rect, nav)) = current else {
+        // Nothing is focused, start from the first entity in tab order,
+        // preferring entities inside an active scope, i.e. an open modal.
+        let order = tab_order(&roots, &root_order, &hierarchy, &focusables);
+        let first = order.iter()
+            .find(|entity| find_scope(**entity, &parents, &scopes).is_some())
+            .or(order.first());
+        if let Some(first) = first {
+            focused.set(*first);
+        }
+        return;
+    };
+    // Text inputs use horizontal arrow keys for their cursor.
+    if direction.y == 0.0 && text_inputs.contains(current) && keys.any_just_pressed([KeyCode::Left, KeyCode::Right]) {
+        return;
+    }
+    if let Some(target) = nav.and_then(|x| x.get(input)) {
+        if rects.get(target).map(|(.., active)| active.is_active()).unwrap_or(false) {
+            focused.set(target);
+        }
+        return;
+    }
+    let scope = find_scope(current, &parents, &scopes);
+    let origin = rect.center();
+    let target = rects.iter()
+        .filter(|(entity, .., active)| *entity != current && active.is_active())
+        .filter(|(entity, ..)| match scope {
+            Some(scope) => *entity == scope || is_descendant(*entity, scope, &parents),
+            None => true,
+        })
+        .filter_map(|(entity, rect, ..)| {
+            let offset = rect.center() - origin;
+            let along = offset.dot(direction);
+            if along <= 0.0 { return None; }
+            let across = offset.perp_dot(direction).abs();
+            // Prefer aligned widgets over closer ones that are off axis.
+            Some((entity, along + across * 2.0))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| entity);
+    if let Some(target) = target {
+        focused.set(target);
+    }
+}
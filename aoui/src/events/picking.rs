@@ -0,0 +1,107 @@
+use bevy::ecs::{entity::Entity, system::{Query, Res, SystemParam}, query::{With, Without}};
+use bevy::math::Vec2;
+use bevy::render::camera::Camera;
+use bevy::transform::components::GlobalTransform;
+use bevy::window::{Window, PrimaryWindow};
+
+use crate::{RotatedRect, Hitbox, Clipping};
+use crate::widgets::clipping::CameraClip;
+
+use super::{AouiCamera, ActiveDetection, InputPlayer};
+
+/// A [`SystemParam`] for point picking and coordinate conversion.
+///
+/// Inactive entities according to [`ActiveDetection`] are ignored by all methods.
+///
+/// * World space is the screen space used by [`RotatedRect`].
+/// * Screen space is the window's logical position, like [`Window::cursor_position`].
+/// * Local space is `0..=1` on the entity's `RotatedRect`, `(0, 0)` being `BottomLeft`.
+#[derive(SystemParam)]
+pub struct AouiPicking<'w, 's> {
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    marked_camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), With<AouiCamera>>,
+    unmarked_camera: Query<'w, 's, (&'static Camera, &'static GlobalTransform), (Without<AouiCamera>, Without<CameraClip>)>,
+    query: Query<'w, 's, (Entity, &'static RotatedRect, Option<&'static Hitbox>, Option<&'static Clipping>, ActiveDetection)>,
+    player: Option<Res<'w, InputPlayer>>,
+}
+
+impl AouiPicking<'_, '_> {
+
+    fn rect(&self, entity: Entity) -> Option<&RotatedRect> {
+        match self.query.get(entity) {
+            Ok((_, rect, .., active)) if active.is_active() => Some(rect),
+            _ => None,
+        }
+    }
+
+    /// Convert a window position to world space.
+    pub fn screen_to_world(&self, position: Vec2) -> Option<Vec2> {
+        let (camera, camera_transform) = match self.marked_camera.get_single() {
+            Ok((cam, transform)) => (cam, transform),
+            Err(_) => self.unmarked_camera.get_single().ok()?,
+        };
+        camera.viewport_to_world(camera_transform, position)
+            .map(|ray| ray.origin.truncate())
+    }
+
+    /// Obtain the cursor position in world space,
+    /// this is the recorded position if an [`InputPlayer`] is playing.
+    pub fn cursor_position(&self) -> Option<Vec2> {
+        if let Some(position) = self.player.as_ref().and_then(|x| x.cursor_position()) {
+            return Some(position);
+        }
+        let window = self.windows.get_single().ok()?;
+        self.screen_to_world(window.cursor_position()?)
+    }
+
+    /// Find all entities under a world space point, topmost first.
+    ///
+    /// This uses [`Hitbox`] if present, and the entire rectangle if not.
+    pub fn pick_all(&self, position: Vec2) -> Vec<Entity> {
+        let mut result: Vec<_> = self.query.iter()
+            .filter(|(.., active)| active.is_active())
+            .filter(|(_, rect, hitbox, clipping, _)| {
+                hitbox.unwrap_or(&Hitbox::FULL).contains(rect, position)
+                    && clipping.map(|x| x.contains(position)).unwrap_or(true)
+            })
+            .map(|(entity, rect, ..)| (entity, rect.z))
+            .collect();
+        result.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        result.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    /// Find the topmost entity under a world space point.
+    pub fn pick(&self, position: Vec2) -> Option<Entity> {
+        self.pick_all(position).into_iter().next()
+    }
+
+    /// Find all entities under a window position, topmost first.
+    pub fn pick_all_screen(&self, position: Vec2) -> Vec<Entity> {
+        match self.screen_to_world(position) {
+            Some(position) => self.pick_all(position),
+            None => Vec::new(),
+        }
+    }
+
+    /// Find the topmost entity under a window position.
+    pub fn pick_screen(&self, position: Vec2) -> Option<Entity> {
+        self.pick(self.screen_to_world(position)?)
+    }
+
+    /// Convert a world space point to an entity's local space.
+    pub fn world_to_local(&self, entity: Entity, position: Vec2) -> Option<Vec2> {
+        let rect = self.rect(entity)?;
+        Some(rect.affine.inverse().transform_point2(position) + Vec2::splat(0.5))
+    }
+
+    /// Convert a point in an entity's local space to world space.
+    pub fn local_to_world(&self, entity: Entity, position: Vec2) -> Option<Vec2> {
+        let rect = self.rect(entity)?;
+        Some(rect.affine.transform_point2(position - Vec2::splat(0.5)))
+    }
+
+    /// Obtain world space corners of an entity, in order `BottomLeft`, `BottomRight`, `TopRight`, `TopLeft`.
+    pub fn corners(&self, entity: Entity) -> Option<[Vec2; 4]> {
+        Some(self.rect(entity)?.corners())
+    }
+}
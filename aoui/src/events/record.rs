@@ -0,0 +1,384 @@
+use std::time::Duration;
+
+use bevy::ecs::entity::Entity;
+use bevy::ecs::event::{EventReader, EventWriter};
+use bevy::ecs::system::{Query, Res, ResMut, Resource};
+use bevy::ecs::query::{With, Without};
+use bevy::input::{Input, keyboard::KeyCode, mouse::{MouseButton, MouseWheel, MouseScrollUnit}};
+use bevy::math::Vec2;
+use bevy::render::camera::Camera;
+use bevy::time::{Time, TimeUpdateStrategy};
+use bevy::transform::components::GlobalTransform;
+use bevy::window::{Window, PrimaryWindow, ReceivedCharacter};
+
+use crate::widgets::clipping::CameraClip;
+
+use super::AouiCamera;
+
+/// Inputs consumed by the event pipeline in a single frame.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputFrame {
+    /// Time in seconds since the recording started.
+    pub time: f32,
+    /// Cursor position in world space.
+    pub cursor: Option<Vec2>,
+    /// Pressed mouse buttons.
+    pub buttons: Vec<MouseButton>,
+    /// Mouse buttons pressed this frame, including ones released in the same frame.
+    pub buttons_just_pressed: Vec<MouseButton>,
+    /// Mouse buttons released this frame, including ones pressed in the same frame.
+    pub buttons_just_released: Vec<MouseButton>,
+    /// Pressed keys.
+    pub keys: Vec<KeyCode>,
+    /// Keys pressed this frame, including ones released in the same frame.
+    pub keys_just_pressed: Vec<KeyCode>,
+    /// Keys released this frame, including ones pressed in the same frame.
+    pub keys_just_released: Vec<KeyCode>,
+    /// Mouse wheel events.
+    pub wheel: Vec<(MouseScrollUnit, Vec2)>,
+    /// Received characters.
+    pub chars: Vec<char>,
+}
+
+/// A sequence of recorded [`InputFrame`]s.
+///
+/// With the `serde` feature, recordings can be saved and loaded as regression fixtures.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecording {
+    pub frames: Vec<InputFrame>,
+}
+
+/// Insert this resource to record inputs consumed by the event pipeline every frame.
+#[derive(Debug, Resource, Default)]
+pub struct InputRecorder {
+    start: Option<f32>,
+    recording: InputRecording,
+}
+
+impl InputRecorder {
+    /// Obtain the recording so far.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Take the recording and start over.
+    pub fn take(&mut self) -> InputRecording {
+        self.start = None;
+        std::mem::take(&mut self.recording)
+    }
+}
+
+/// Insert this resource to replay an [`InputRecording`], one frame per update.
+///
+/// The player writes to `Input<MouseButton>`, `Input<KeyCode>`,
+/// and sends `MouseWheel` and `ReceivedCharacter` events,
+/// while the recorded cursor position replaces the window's,
+/// including in [`CustomCursor`](super::CustomCursor) and [`AouiPicking`](super::AouiPicking).
+/// Since cursor positions are in world space, no window or camera is needed,
+/// so this can be used in a headless app with bevy's `InputPlugin` and `WindowPlugin`
+/// for the input resources and events.
+#[derive(Debug, Resource)]
+pub struct InputPlayer {
+    recording: InputRecording,
+    current: Option<usize>,
+    next: usize,
+    recorded_time: bool,
+}
+
+impl InputPlayer {
+    pub fn new(recording: InputRecording) -> Self {
+        Self { recording, current: None, next: 0, recorded_time: false }
+    }
+
+    /// Advance `Time` by recorded timestamps via `TimeUpdateStrategy`,
+    /// this makes time based events like `DoubleClick` deterministic.
+    pub fn with_recorded_time(mut self) -> Self {
+        self.recorded_time = true;
+        self
+    }
+
+    /// Returns true if all frames have been played.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.recording.frames.len()
+    }
+
+    /// Index of the frame being played.
+    pub fn frame(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Recorded cursor position of the frame being played.
+    pub fn cursor_position(&self) -> Option<Vec2> {
+        self.recording.frames.get(self.current?)?.cursor
+    }
+
+    fn advance(&mut self) -> Option<&InputFrame> {
+        self.current = (self.next < self.recording.frames.len()).then_some(self.next);
+        self.next += 1;
+        self.recording.frames.get(self.current?)
+    }
+
+    fn next_delta(&self) -> Option<f32> {
+        let next = self.recording.frames.get(self.next)?.time;
+        let last = match self.next {
+            0 => 0.0,
+            n => self.recording.frames[n - 1].time,
+        };
+        Some((next - last).max(0.0))
+    }
+}
+
+/// Apply `press` and `release` so that exactly `pressed` are pressed,
+/// and `just_pressed` and `just_released` match the recording.
+fn sync_input<T: Copy + Eq + std::hash::Hash + Send + Sync + 'static>(
+    input: &mut Input<T>,
+    pressed: &[T],
+    just_pressed: &[T],
+    just_released: &[T],
+) {
+    input.clear();
+    // Replays presses shorter than a frame and releases followed by a press.
+    for item in just_pressed {
+        input.press(*item);
+    }
+    for item in just_released {
+        input.release(*item);
+    }
+    let released: Vec<_> = input.get_pressed().filter(|x| !pressed.contains(x)).copied().collect();
+    for item in released {
+        input.release(item);
+    }
+    for item in pressed {
+        input.press(*item);
+    }
+}
+
+/// Set `TimeUpdateStrategy` to the recorded timestamps, runs before `TimeSystem`.
+pub fn playback_time(
+    player: Option<Res<InputPlayer>>,
+    strategy: Option<ResMut<TimeUpdateStrategy>>,
+) {
+    let (Some(player), Some(mut strategy)) = (player, strategy) else { return };
+    if !player.recorded_time { return; }
+    if let Some(delta) = player.next_delta() {
+        *strategy = TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(delta));
+    }
+}
+
+/// Feed the current frame of [`InputPlayer`] into bevy's inputs.
+pub fn input_playback(
+    player: Option<ResMut<InputPlayer>>,
+    mut buttons: ResMut<Input<MouseButton>>,
+    mut keys: ResMut<Input<KeyCode>>,
+    mut wheel: EventWriter<MouseWheel>,
+    mut chars: EventWriter<ReceivedCharacter>,
+    windows: Query<Entity, With<PrimaryWindow>>,
+) {
+    let Some(mut player) = player else { return };
+    let Some(frame) = player.advance() else { return };
+    let window = windows.get_single().unwrap_or(Entity::PLACEHOLDER);
+    sync_input(&mut buttons, &frame.buttons, &frame.buttons_just_pressed, &frame.buttons_just_released);
+    sync_input(&mut keys, &frame.keys, &frame.keys_just_pressed, &frame.keys_just_released);
+    wheel.send_batch(frame.wheel.iter().map(|(unit, delta)| MouseWheel {
+        unit: *unit,
+        x: delta.x,
+        y: delta.y,
+        window,
+    }));
+    chars.send_batch(frame.chars.iter().map(|char| ReceivedCharacter {
+        window,
+        char: *char,
+    }));
+}
+
+/// Record inputs into [`InputRecorder`].
+pub fn input_recording(
+    recorder: Option<ResMut<InputRecorder>>,
+    player: Option<Res<InputPlayer>>,
+    time: Res<Time>,
+    buttons: Res<Input<MouseButton>>,
+    keys: Res<Input<KeyCode>>,
+    mut wheel: EventReader<MouseWheel>,
+    mut chars: EventReader<ReceivedCharacter>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    marked_camera: Query<(&Camera, &GlobalTransform), With<AouiCamera>>,
+    unmarked_camera: Query<(&Camera, &GlobalTransform), (Without<AouiCamera>, Without<CameraClip>)>,
+) {
+    let Some(mut recorder) = recorder else {
+        wheel.clear();
+        chars.clear();
+        return;
+    };
+    let camera = match marked_camera.get_single() {
+        Ok(camera) => Some(camera),
+        Err(_) => unmarked_camera.get_single().ok(),
+    };
+    let cursor = player.and_then(|x| x.cursor_position()).or_else(|| windows.get_single().ok()
+        .and_then(|window| window.cursor_position())
+        .zip(camera)
+        .and_then(|(cursor, (camera, camera_transform))| camera.viewport_to_world_2d(camera_transform, cursor)));
+    let start = *recorder.start.get_or_insert(time.elapsed_seconds());
+    recorder.recording.frames.push(InputFrame {
+        time: time.elapsed_seconds() - start,
+        cursor,
+        buttons: buttons.get_pressed().copied().collect(),
+        buttons_just_pressed: buttons.get_just_pressed().copied().collect(),
+        buttons_just_released: buttons.get_just_released().copied().collect(),
+        keys: keys.get_pressed().copied().collect(),
+        keys_just_pressed: keys.get_just_pressed().copied().collect(),
+        keys_just_released: keys.get_just_released().copied().collect(),
+        wheel: wheel.read().map(|x| (x.unit, Vec2::new(x.x, x.y))).collect(),
+        chars: chars.read().map(|x| x.char).collect(),
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::MinimalPlugins;
+    use bevy::app::{App, PreUpdate};
+    use bevy::asset::{AssetApp, AssetPlugin};
+    use bevy::ecs::{component::Component, entity::Entity, schedule::IntoSystemConfigs};
+    use bevy::hierarchy::HierarchyPlugin;
+    use bevy::input::{Input, InputPlugin, InputSystem, mouse::MouseButton};
+    use bevy::math::{Affine2, Vec2};
+    use bevy::render::{texture::Image, view::{Visibility, InheritedVisibility}};
+    use bevy::sprite::TextureAtlas;
+    use bevy::text::Font;
+    use bevy::time::Time;
+    use bevy::window::{ReceivedCharacter, WindowPlugin};
+
+    use crate::bundles::AouiBundle;
+    use crate::{AouiPlugin, Anchor, RotatedRect, Hitbox, Clipping, Opacity};
+    use crate::events::{Handlers, EvLeftClick, Mutation};
+    use crate::events::{CursorAction, CursorFocus, CursorState, DoubleClickThreshold, DragThreshold, EventFlags, TouchState};
+    use super::super::systems::mouse_button_input;
+    use super::{InputFrame, InputPlayer, InputRecording, input_playback};
+
+    fn app(recording: InputRecording) -> App {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin)
+            .add_event::<ReceivedCharacter>()
+            .init_resource::<Time>()
+            .init_resource::<CursorState>()
+            .init_resource::<DoubleClickThreshold>()
+            .insert_resource(DragThreshold::pixels(5.0))
+            .init_resource::<TouchState>()
+            .insert_resource(InputPlayer::new(recording))
+            .add_systems(PreUpdate, (input_playback, mouse_button_input).chain().after(InputSystem));
+        app
+    }
+
+    fn spawn(app: &mut App, flags: EventFlags) -> Entity {
+        app.world.spawn((
+            flags,
+            RotatedRect {
+                affine: Affine2::from_scale(Vec2::splat(100.0)),
+                ..Default::default()
+            },
+            Hitbox::default(),
+            Clipping::new(false),
+            Visibility::Inherited,
+            InheritedVisibility::VISIBLE,
+            Opacity::default(),
+        )).id()
+    }
+
+    fn frame(cursor: Vec2, buttons: &[MouseButton], just_pressed: &[MouseButton], just_released: &[MouseButton]) -> InputFrame {
+        InputFrame {
+            cursor: Some(cursor),
+            buttons: buttons.to_vec(),
+            buttons_just_pressed: just_pressed.to_vec(),
+            buttons_just_released: just_released.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    const LEFT: &[MouseButton] = &[MouseButton::Left];
+
+    #[test]
+    fn replay_click() {
+        let mut app = app(InputRecording { frames: vec![
+            frame(Vec2::ZERO, &[], &[], &[]),
+            frame(Vec2::ZERO, LEFT, LEFT, &[]),
+            frame(Vec2::ZERO, &[], &[], LEFT),
+            frame(Vec2::ZERO, &[], LEFT, LEFT),
+        ]});
+        let entity = spawn(&mut app, EventFlags::LeftClick);
+        app.update();
+        assert!(app.world.get::<CursorAction>(entity).is_none());
+        app.update();
+        assert_eq!(app.world.get::<CursorAction>(entity).map(|x| x.flags()), Some(EventFlags::LeftDown));
+        assert_eq!(app.world.get::<CursorFocus>(entity).map(|x| x.flags()), Some(EventFlags::LeftPressed));
+        app.update();
+        assert_eq!(app.world.get::<CursorAction>(entity).map(|x| x.flags()), Some(EventFlags::LeftClick));
+        // A click shorter than a frame.
+        app.update();
+        let buttons = app.world.resource::<Input<MouseButton>>();
+        assert!(!buttons.pressed(MouseButton::Left));
+        assert!(buttons.just_pressed(MouseButton::Left));
+        assert!(buttons.just_released(MouseButton::Left));
+        assert!(app.world.resource::<InputPlayer>().is_finished());
+    }
+
+    #[test]
+    fn replay_drag() {
+        let mut app = app(InputRecording { frames: vec![
+            frame(Vec2::ZERO, LEFT, LEFT, &[]),
+            frame(Vec2::new(2.0, 0.0), LEFT, &[], &[]),
+            frame(Vec2::new(20.0, 0.0), LEFT, &[], &[]),
+            frame(Vec2::new(20.0, 0.0), &[], &[], LEFT),
+        ]});
+        let entity = spawn(&mut app, EventFlags::LeftDrag);
+        app.update();
+        assert_eq!(app.world.get::<CursorAction>(entity).map(|x| x.flags()), Some(EventFlags::LeftDown));
+        app.update();
+        assert_eq!(app.world.get::<CursorFocus>(entity).map(|x| x.flags()), Some(EventFlags::LeftPressed));
+        app.update();
+        assert_eq!(app.world.get::<CursorFocus>(entity).map(|x| x.flags()), Some(EventFlags::LeftDrag));
+        assert!(app.world.resource::<CursorState>().drag_just_started());
+        app.update();
+        assert_eq!(app.world.get::<CursorAction>(entity).map(|x| x.flags()), Some(EventFlags::DragEnd));
+        assert!(!app.world.resource::<CursorState>().dragging());
+    }
+
+    #[derive(Debug, Default, Component)]
+    struct Clicks(u32);
+
+    #[test]
+    fn replay_plugin_click() {
+        // The layout is computed at the end of the first frame.
+        let recording = InputRecording { frames: vec![
+            frame(Vec2::ZERO, &[], &[], &[]),
+            frame(Vec2::ZERO, LEFT, LEFT, &[]),
+            frame(Vec2::ZERO, &[], &[], LEFT),
+            frame(Vec2::new(200.0, 0.0), LEFT, LEFT, &[]),
+            frame(Vec2::new(200.0, 0.0), &[], &[], LEFT),
+        ]};
+        #[cfg(feature="serde")]
+        let recording: InputRecording = serde_json::from_str(&serde_json::to_string(&recording).unwrap()).unwrap();
+        let mut app = App::new();
+        // The primary window is an entity without a backend, it sizes the root of the layout.
+        app.add_plugins((MinimalPlugins, AssetPlugin::default(), InputPlugin, HierarchyPlugin, WindowPlugin::default()))
+            .init_asset::<Image>()
+            .init_asset::<Font>()
+            .init_asset::<TextureAtlas>()
+            .add_plugins(AouiPlugin)
+            .insert_resource(InputPlayer::new(recording));
+        let entity = app.world.spawn((
+            AouiBundle::empty(Anchor::Center, Vec2::splat(100.0)),
+            EventFlags::LeftClick,
+            Hitbox::default(),
+            Clicks::default(),
+            Handlers::<EvLeftClick>::new(Mutation::new(|clicks: &mut Clicks| clicks.0 += 1)),
+        )).id();
+        // Visibility is propagated by the renderer.
+        app.world.entity_mut(entity).insert(InheritedVisibility::VISIBLE);
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world.resource::<InputPlayer>().is_finished());
+        assert_eq!(app.world.get::<Clicks>(entity).unwrap().0, 1);
+    }
+}
@@ -0,0 +1,140 @@
+use bevy::ecs::{entity::Entity, system::{Resource, Commands, CommandQueue, IntoSystem, SystemId}, world::World};
+use bevy::log::error;
+use bevy::utils::HashMap;
+
+use crate::signals::{AsObject, Object, SignalSender};
+
+use super::{mutation::{Mutation, IntoMutationCommand}, oneshot::OneShot};
+
+/// A named handler in [`HandlerRegistry`].
+#[derive(Debug)]
+pub enum NamedHandler {
+    /// Run a oneshot system.
+    OneShotSystem(OneShot),
+    /// Run a registered system.
+    System(SystemId),
+    /// Mutate components associated with the calling entity.
+    ///
+    /// Data is type erased, use `Mutation::dynamic` to specify the data type.
+    Mutation(Mutation<Object>),
+    /// Send a signal with the associated data.
+    Signal(SignalSender<Object>),
+}
+
+/// Handlers referenced by name via [`Handler::Named`](super::Handler::Named),
+/// allows event wiring from data, i.e. a [`UiScene`](crate::scene::UiScene).
+///
+/// Names are resolved when the event is handled,
+/// unregistered names are reported as errors.
+#[derive(Debug, Default, Resource)]
+pub struct HandlerRegistry(HashMap<String, NamedHandler>);
+
+impl HandlerRegistry {
+
+    /// Register a handler, returns the previous handler with the same name.
+    pub fn register(&mut self, name: impl Into<String>, handler: NamedHandler) -> Option<NamedHandler> {
+        self.0.insert(name.into(), handler)
+    }
+
+    /// Register a oneshot system.
+    pub fn register_oneshot<M: Send + Sync + 'static>(
+        &mut self,
+        commands: &mut Commands,
+        name: impl Into<String>,
+        system: impl IntoSystem<(), (), M> + Send + Sync + 'static
+    ) -> &mut Self {
+        self.register(name, NamedHandler::OneShotSystem(OneShot::new(commands, system)));
+        self
+    }
+
+    /// Register an already registered system.
+    pub fn register_system(&mut self, name: impl Into<String>, system: SystemId) -> &mut Self {
+        self.register(name, NamedHandler::System(system));
+        self
+    }
+
+    /// Register a mutation that does not use the event's data.
+    pub fn register_mutation<M, N>(
+        &mut self,
+        name: impl Into<String>,
+        mutation: impl IntoMutationCommand<Object, M, N>
+    ) -> &mut Self {
+        self.register(name, NamedHandler::Mutation(Mutation::new(mutation)));
+        self
+    }
+
+    /// Register a mutation that downcasts the event's data to `T`.
+    pub fn register_dyn_mutation<T: AsObject, M, N>(
+        &mut self,
+        name: impl Into<String>,
+        mutation: impl IntoMutationCommand<T, M, N>
+    ) -> &mut Self {
+        self.register(name, NamedHandler::Mutation(Mutation::dynamic(mutation)));
+        self
+    }
+
+    /// Register a signal endpoint.
+    pub fn register_signal<T: AsObject>(&mut self, name: impl Into<String>, signal: SignalSender<T>) -> &mut Self {
+        self.register(name, NamedHandler::Signal(signal.type_erase()));
+        self
+    }
+
+    /// Remove a handler by name.
+    pub fn remove(&mut self, name: &str) -> Option<NamedHandler> {
+        self.0.remove(name)
+    }
+
+    /// Returns true if a handler with this name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Get a handler by name.
+    pub fn get(&self, name: &str) -> Option<&NamedHandler> {
+        self.0.get(name)
+    }
+}
+
+/// Resolve and run a named handler when commands are applied.
+///
+/// Systems are run after the registry is released,
+/// so they can access and modify the [`HandlerRegistry`].
+pub(crate) fn run_named_handler(commands: &mut Commands, entity: Entity, name: String, data: Object) {
+    commands.add(move |world: &mut World| {
+        let Some(registry) = world.get_resource::<HandlerRegistry>() else {
+            error!("Handler \"{}\" cannot be resolved, `HandlerRegistry` does not exist.", name);
+            return;
+        };
+        let Some(handler) = registry.get(&name) else {
+            error!("Handler \"{}\" is not registered in `HandlerRegistry`, called by {:?}.", name, entity);
+            return;
+        };
+        let system = match handler {
+            NamedHandler::OneShotSystem(system) => match system.get() {
+                Some(system) => system,
+                None => {
+                    error!("Named handler \"{}\" is not initialized.", name);
+                    return;
+                },
+            },
+            NamedHandler::System(system) => *system,
+            NamedHandler::Mutation(mutation) => {
+                if world.get_entity(entity).is_none() {
+                    return;
+                }
+                let mut queue = CommandQueue::default();
+                let mut commands = Commands::new(&mut queue, world);
+                mutation.exec(&mut commands.entity(entity), data);
+                queue.apply(world);
+                return;
+            },
+            NamedHandler::Signal(signal) => {
+                signal.send_dyn(data);
+                return;
+            },
+        };
+        if let Err(err) = world.run_system(system) {
+            error!("Named handler \"{}\" failed: {}.", name, err);
+        }
+    });
+}
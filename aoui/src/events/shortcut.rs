@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res, ResMut, Commands};
+use bevy::ecs::query::{With, Changed};
+use bevy::hierarchy::Parent;
+use bevy::input::{Input, keyboard::KeyCode};
+use bevy::reflect::Reflect;
+use bevy::text::Text;
+
+use crate::dsl::{DslInto, WidgetWrite};
+use crate::signals::KeyStorage;
+use crate::widgets::inputbox::InputBox;
+
+use super::{ActiveDetection, FocusedEntity, Handlers, EvShortcut};
+use super::navigation::{NavigationScope, find_scope, is_descendant};
+
+/// A key with modifiers, i.e. `Ctrl+S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub struct KeyChord {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    pub super_key: bool,
+}
+
+impl KeyChord {
+    pub const fn new(key: KeyCode) -> Self {
+        Self { key, ctrl: false, shift: false, alt: false, super_key: false }
+    }
+
+    pub const fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    pub const fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    pub const fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    pub const fn super_key(mut self) -> Self {
+        self.super_key = true;
+        self
+    }
+
+    /// Returns true if the key is just pressed with exactly these modifiers.
+    pub fn just_pressed(&self, keys: &Input<KeyCode>) -> bool {
+        keys.just_pressed(self.key)
+            && self.ctrl == keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+            && self.shift == keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+            && self.alt == keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+            && self.super_key == keys.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight])
+    }
+
+    /// Returns false if this chord can be typed in a text input.
+    fn is_command(&self) -> bool {
+        use KeyCode::*;
+        self.ctrl || self.alt || self.super_key || matches!(self.key,
+            Escape | F1 | F2 | F3 | F4 | F5 | F6 | F7 | F8 | F9 | F10 | F11 | F12
+        )
+    }
+}
+
+impl From<KeyCode> for KeyChord {
+    fn from(key: KeyCode) -> Self {
+        Self::new(key)
+    }
+}
+
+impl Display for KeyChord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.ctrl { f.write_str("Ctrl+")?; }
+        if self.alt { f.write_str("Alt+")?; }
+        if self.shift { f.write_str("Shift+")?; }
+        if self.super_key { f.write_str("Super+")?; }
+        let key = format!("{:?}", self.key);
+        // `Key1` to `1`.
+        match key.strip_prefix("Key") {
+            Some(digit) => f.write_str(digit),
+            None => f.write_str(&key),
+        }
+    }
+}
+
+/// Where [`Shortcuts`] are active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum ShortcutScope {
+    /// Active anywhere, unless keyboard focus is inside
+    /// a [`NavigationScope`] that does not contain this entity, i.e. a modal.
+    #[default]
+    Global,
+    /// Active if this entity or one of its descendants has keyboard focus.
+    Focused,
+}
+
+/// Maps [`KeyChord`]s to [`Handlers<EvShortcut>`].
+///
+/// If multiple active entities bind the same chord,
+/// only the one deepest in the hierarchy is run.
+/// Chords without `Ctrl`, `Alt` or `Super` are ignored while an `InputBox` has focus,
+/// except `Escape` and function keys.
+///
+/// The key of a chord that runs is consumed, so `Tab`, `Enter` or arrow keys bound as
+/// shortcuts do not also move keyboard focus or activate the focused widget.
+#[derive(Debug, Component, Default)]
+pub struct Shortcuts {
+    pub scope: ShortcutScope,
+    pub bindings: Vec<(KeyChord, Handlers<EvShortcut>)>,
+}
+
+impl Shortcuts {
+    /// Shortcuts active anywhere.
+    pub fn global() -> Self {
+        Self { scope: ShortcutScope::Global, bindings: Vec::new() }
+    }
+
+    /// Shortcuts active when this entity or one of its descendants has keyboard focus.
+    pub fn focused() -> Self {
+        Self { scope: ShortcutScope::Focused, bindings: Vec::new() }
+    }
+
+    /// Bind a chord to handlers in a builder pattern.
+    pub fn with(mut self, chord: impl Into<KeyChord>, handlers: impl DslInto<Handlers<EvShortcut>>) -> Self {
+        self.bindings.push((chord.into(), handlers.dinto()));
+        self
+    }
+
+    /// Find the first chord bound, for displaying in menu items.
+    pub fn chord(&self) -> Option<KeyChord> {
+        self.bindings.first().map(|(chord, _)| *chord)
+    }
+}
+
+/// Writes the text of a [`KeyChord`] to a text widget, i.e. `Ctrl+S` in a menu item.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+pub struct ShortcutLabel(pub KeyChord);
+
+fn depth(entity: Entity, parents: &Query<&Parent>) -> usize {
+    let mut current = entity;
+    let mut depth = 0;
+    while let Ok(parent) = parents.get(current) {
+        current = parent.get();
+        depth += 1;
+    }
+    depth
+}
+
+/// Run [`Shortcuts`] of just pressed [`KeyChord`]s, runs before keyboard navigation
+/// and consumes the key with `clear_just_pressed`.
+pub fn shortcut_input(
+    mut commands: Commands,
+    storage: Res<KeyStorage>,
+    mut keys: ResMut<Input<KeyCode>>,
+    focused: Res<FocusedEntity>,
+    parents: Query<&Parent>,
+    scopes: Query<(), With<NavigationScope>>,
+    text_inputs: Query<&InputBox>,
+    query: Query<(Entity, &Shortcuts, ActiveDetection)>,
+) {
+    if keys.get_just_pressed().next().is_none() { return; }
+    let focus = focused.get();
+    let modal = focus.and_then(|entity| find_scope(entity, &parents, &scopes));
+    let typing = text_inputs.iter().any(|x| x.has_focus());
+    let contains_focus = |entity: Entity| match focus {
+        Some(focus) => focus == entity || is_descendant(focus, entity, &parents),
+        None => false,
+    };
+    let candidate = query.iter()
+        .filter(|(.., active)| active.is_active())
+        .filter(|(entity, shortcuts, _)| match shortcuts.scope {
+            ShortcutScope::Focused => contains_focus(*entity),
+            ShortcutScope::Global => match modal {
+                Some(modal) => *entity == modal || is_descendant(*entity, modal, &parents),
+                None => true,
+            },
+        })
+        .filter_map(|(entity, shortcuts, _)| shortcuts.bindings.iter()
+            .find(|(chord, _)| chord.just_pressed(&keys) && (!typing || chord.is_command()))
+            .map(|(chord, handlers)| (entity, *chord, handlers)))
+        .max_by_key(|(entity, ..)| depth(*entity, &parents));
+    if let Some((entity, chord, handlers)) = candidate {
+        handlers.handle(&mut commands.entity(entity), &storage, chord);
+        keys.clear_just_pressed(chord.key);
+    }
+}
+
+/// Write [`ShortcutLabel`]s to their text.
+pub fn shortcut_label(mut query: Query<(&ShortcutLabel, &mut Text), Changed<ShortcutLabel>>) {
+    for (label, mut text) in query.iter_mut() {
+        text.write(label.0.to_string());
+    }
+}
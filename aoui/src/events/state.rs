@@ -1,4 +1,6 @@
-use bevy::{ecs::{system::{Resource, Commands, EntityCommands}, entity::Entity}, math::Vec2, input::mouse::MouseButton};
+use bevy::{ecs::{system::{Resource, Commands, EntityCommands}, entity::Entity, component::Component}, math::Vec2, input::mouse::MouseButton};
+use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 
 #[derive(Debug, Resource)]
 pub struct DoubleClickThreshold(f32);
@@ -19,6 +21,42 @@ impl DoubleClickThreshold {
     }
 }
 
+/// Dead zone before a drag begins.
+///
+/// A drag begins when the cursor moves `pixels` away from where it is pressed,
+/// or when the button is held for `time` seconds, a value of `0` disables that condition.
+/// If released inside the widget before the drag begins, the press resolves to a click.
+/// Applies to `Left` and `Other` drags and captured presses.
+///
+/// As a resource this sets the default of all widgets,
+/// as a component this overrides the default for a single widget.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Resource, Component, Reflect)]
+#[reflect(Component, Resource, Default)]
+pub struct DragThreshold {
+    pub pixels: f32,
+    pub time: f32,
+}
+
+impl DragThreshold {
+    /// Drag begins immediately.
+    pub const NONE: Self = Self { pixels: 0.0, time: 0.0 };
+
+    pub const fn pixels(pixels: f32) -> Self {
+        Self { pixels, time: 0.0 }
+    }
+
+    pub const fn time(time: f32) -> Self {
+        Self { pixels: 0.0, time }
+    }
+
+    /// Returns true if a drag should begin.
+    pub fn is_exceeded(&self, distance: f32, time: f32) -> bool {
+        (self.pixels <= 0.0 && self.time <= 0.0)
+            || (self.pixels > 0.0 && distance >= self.pixels)
+            || (self.time > 0.0 && time >= self.time)
+    }
+}
+
 /// State of the cursor system.
 #[derive(Debug, Resource)]
 pub struct CursorState{
@@ -30,9 +68,17 @@ pub struct CursorState{
     pub(super) caught: bool,
     pub(super) dragging: bool,
     pub(super) drag_button: MouseButton,
+    pub(super) other_button: u16,
     pub(super) drag_target: Option<Entity>,
     pub(super) focused: Option<Entity>,
     pub(super) drag_dbl_click: bool,
+    pub(super) drag_pending: bool,
+    pub(super) drag_started: bool,
+    pub(super) drag_down_time: f32,
+    pub(super) captured: Option<Entity>,
+    pub(super) capture_lost: Vec<Entity>,
+    pub(super) capture_drag: bool,
+    pub(super) drag_cancelled: Vec<Entity>,
 }
 
 impl Default for CursorState {
@@ -45,10 +91,18 @@ impl Default for CursorState {
             blocked: false, 
             dragging: false, 
             drag_button: MouseButton::Left, 
+            other_button: 0,
             drag_target: None, 
             focused: None,
             caught: false,
             drag_dbl_click: false,
+            drag_pending: false,
+            drag_started: false,
+            drag_down_time: 0.0,
+            captured: None,
+            capture_lost: Vec::new(),
+            capture_drag: false,
+            drag_cancelled: Vec::new(),
         }
     }
 }
@@ -78,6 +132,8 @@ impl CursorState {
         self.blocked = true;
         self.drag_target = None;
         self.dragging = false;
+        self.drag_pending = false;
+        self.release_pointer();
     }
 
     /// Cancels dragging of the current entity, does not reset mouse state.
@@ -90,6 +146,43 @@ impl CursorState {
         self.last_lmb_down_time = [0.0, 0.0];
     }
 
+    /// Route all cursor events to an entity regardless of hit testing,
+    /// until [`release_pointer`](Self::release_pointer) is called or all mouse buttons are released.
+    ///
+    /// The entity receives `CursorFocus`, `CursorAction` and `MouseWheelAction`.
+    /// The current drag continues as a captured drag if the entity is being dragged,
+    /// otherwise the drag target receives `DragEnd`.
+    /// The previously captured entity receives [`PointerCaptureLost`](super::PointerCaptureLost).
+    pub fn capture_pointer(&mut self, entity: Entity) {
+        if self.captured == Some(entity) { return; }
+        self.release_pointer();
+        let drag = self.dragging && !self.drag_pending;
+        if let Some(target) = self.drag_target.filter(|target| drag && *target != entity) {
+            self.drag_cancelled.push(target);
+        }
+        self.capture_drag = drag && self.drag_target == Some(entity);
+        self.drag_target = None;
+        self.dragging = false;
+        self.drag_pending = false;
+        self.captured = Some(entity);
+    }
+
+    /// Release pointer capture, the captured entity receives [`PointerCaptureLost`](super::PointerCaptureLost),
+    /// and `DragEnd` if dragged.
+    pub fn release_pointer(&mut self) {
+        if let Some(entity) = self.captured.take() {
+            if std::mem::take(&mut self.capture_drag) {
+                self.drag_cancelled.push(entity);
+            }
+            self.capture_lost.push(entity);
+        }
+    }
+
+    /// Obtain the entity capturing the pointer.
+    pub fn pointer_capture(&self) -> Option<Entity> {
+        self.captured
+    }
+
     /// This guarantees the existence of the entity.
     pub fn drag_target<'w, 's, 't>(&self, commands: &'t mut Commands<'w, 's>) -> Option<EntityCommands<'w, 's, 't>> {
         commands.get_entity(self.drag_target?)
@@ -107,11 +200,24 @@ impl CursorState {
         self.cursor_pos
     }
 
+    /// Returns true if dragging with `Left` or `Other`, excluding presses under the [`DragThreshold`].
     pub fn dragging(&self) -> bool {
-        self.dragging
+        self.dragging && !self.drag_pending
+    }
+
+    /// Returns true on the frame the drag leaves the [`DragThreshold`],
+    /// i.e. the first frame `*Drag` is sent to the drag target.
+    pub fn drag_just_started(&self) -> bool {
+        self.drag_started
     }
 
     pub fn drag_button(&self) -> MouseButton {
         self.drag_button
     }
+
+    /// Index of the last pressed `MouseButton::Other`, i.e. back or forward,
+    /// sent as data by `OtherDown`, `OtherPressed`, `OtherClick` and `OtherDrag`.
+    pub fn other_button(&self) -> u16 {
+        self.other_button
+    }
 }
\ No newline at end of file
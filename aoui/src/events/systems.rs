@@ -1,16 +1,20 @@
 use bevy::{prelude::*, window::{Window, PrimaryWindow}};
 
 use crate::widgets::clipping::CameraClip;
+use crate::widgets::dnd::{DragPayload, AcceptDrop};
 
 use super::*;
 
 
-/// Remove [`CursorFocus`], [`CursorAction`], [`CursorClickOutside`] and [`Submit`];
+/// Remove [`CursorFocus`], [`CursorAction`], [`CursorClickOutside`], [`PointerCaptureLost`], 
+/// [`MouseWheelAction`], [`GestureAction`], [`EventConsumed`] and [`BubbledAction`].
 pub fn remove_focus(mut commands: Commands, 
     query1: Query<Entity, With<CursorFocus>>, 
     query2: Query<Entity, With<CursorAction>>,
-    query3: Query<Entity, With<CursorClickOutside>>,
+    query3: Query<Entity, Or<(With<CursorClickOutside>, With<PointerCaptureLost>)>>,
     query4: Query<Entity, With<MouseWheelAction>>,
+    query5: Query<Entity, With<GestureAction>>,
+    query6: Query<Entity, Or<(With<EventConsumed>, With<BubbledAction>)>>,
 ) {
     for entity in query1.iter() {
         commands.entity(entity).remove::<CursorFocus>();
@@ -19,11 +23,32 @@ pub fn remove_focus(mut commands: Commands,
         commands.entity(entity).remove::<CursorAction>();
     }
     for entity in query3.iter() {
-        commands.entity(entity).remove::<CursorClickOutside>();
+        commands.entity(entity).remove::<(CursorClickOutside, PointerCaptureLost)>();
     }
     for entity in query4.iter() {
         commands.entity(entity).remove::<MouseWheelAction>();
     }
+    for entity in query5.iter() {
+        commands.entity(entity).remove::<GestureAction>();
+    }
+    for entity in query6.iter() {
+        commands.entity(entity).remove::<(EventConsumed, BubbledAction)>();
+    }
+}
+
+/// Send [`PointerCaptureLost`] to entities that lost pointer capture,
+/// and `DragEnd` to drags ended by capturing or releasing the pointer.
+pub fn pointer_capture_lost(mut commands: Commands, mut state: ResMut<CursorState>) {
+    for entity in std::mem::take(&mut state.drag_cancelled) {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(CursorAction(EventFlags::DragEnd));
+        }
+    }
+    for entity in std::mem::take(&mut state.capture_lost) {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(PointerCaptureLost);
+        }
+    }
 }
 
 trait OptionDo<T> {
@@ -50,6 +75,14 @@ trait End: Sized {
 
 impl<T> End for T {}
 
+/// Find the index of a `MouseButton::Other` in buttons.
+fn find_other<'t>(mut buttons: impl Iterator<Item = &'t MouseButton>) -> Option<u16> {
+    buttons.find_map(|button| match button {
+        MouseButton::Other(index) => Some(*index),
+        _ => None,
+    })
+}
+
 /// We hand out component [`CursorFocus`] for persistant states,
 /// [`CursorAction`] for active events.
 /// and [`CursorClickOutside`] for cancelling.
@@ -59,11 +92,16 @@ pub fn mouse_button_input(
     mut state: ResMut<CursorState>,
     time: Res<Time>,
     double_click: Res<DoubleClickThreshold>,
+    drag_threshold: Res<DragThreshold>,
     buttons: Res<Input<MouseButton>>,
+    touch: Res<TouchState>,
     windows: Query<&Window, With<PrimaryWindow>>,
     marked_camera: Query<(&Camera, &GlobalTransform), With<AouiCamera>>,
     unmarked_camera: Query<(&Camera, &GlobalTransform), (Without<AouiCamera>, Without<CameraClip>)>,
     query: Query<(Entity, &EventFlags, CursorDetection, ActiveDetection)>,
+    thresholds: Query<&DragThreshold>,
+    drops: Query<(Option<&DragPayload>, Option<&AcceptDrop>)>,
+    player: Option<Res<InputPlayer>>,
 ) {
     let iter = |f: EventFlags|query.iter().filter_map(move |(entity, flag, cursor, detection)| {
         if detection.is_active() && flag.intersects(f) {
@@ -72,41 +110,170 @@ pub fn mouse_button_input(
             None
         }
     });
+    // `AcceptDrop` refuses `Drop` if it refuses the dragged widget's payload.
+    let accepts = |source: Entity, target: Entity| match drops.get(target) {
+        Ok((_, Some(accept))) => drops.get(source).ok()
+            .and_then(|(payload, _)| payload)
+            .map(|payload| accept.accepts(&payload.0))
+            .unwrap_or(false),
+        _ => true,
+    };
     state.caught = false;
     state.focused = None;
+    state.drag_started = false;
     if state.blocked { return; }
-    let(camera, camera_transform) = match marked_camera.get_single() {
-        Ok((cam, transform)) => (cam, transform),
-        Err(_) => match unmarked_camera.get_single(){
-            Ok((cam, transform)) => (cam, transform),
-            Err(_) => return,
-        },
+    let (mouse_pos, buttons) = match player.and_then(|x| x.cursor_position()) {
+        // Recorded positions are in world space.
+        Some(position) => (position, buttons.as_ref()),
+        None => {
+            let(camera, camera_transform) = match marked_camera.get_single() {
+                Ok((cam, transform)) => (cam, transform),
+                Err(_) => match unmarked_camera.get_single(){
+                    Ok((cam, transform)) => (cam, transform),
+                    Err(_) => return,
+                },
+            };
+            let Ok(window) = windows.get_single() else { return };
+            // Touches take priority over the mouse.
+            let (cursor, buttons) = match touch.pointer() {
+                Some((position, buttons)) => (Some(position), buttons),
+                None => (window.cursor_position(), buttons.as_ref()),
+            };
+            let Some(mouse_pos) = cursor
+                .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+                .map(|ray| ray.origin.truncate()) else {return;};
+            (mouse_pos, buttons)
+        }
     };
-    let Ok(window) = windows.get_single() else { return };
-    let Some(mouse_pos) = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
-        .map(|ray| ray.origin.truncate()) else {return;};
     state.cursor_pos = mouse_pos;
+    if buttons.get_just_pressed().next().is_some() {
+        state.drag_down_time = time.elapsed_seconds();
+    }
+    if let Some(captured) = state.captured {
+        let Some(mut entity) = commands.get_entity(captured) else {
+            state.release_pointer();
+            return;
+        };
+        state.caught = true;
+        state.focused = Some(captured);
+        // A new press if no button was held before this frame.
+        if buttons.get_pressed().all(|button| buttons.just_pressed(*button)) 
+                && buttons.get_just_pressed().next().is_some() {
+            state.down_pos = mouse_pos;
+            state.capture_drag = false;
+        }
+        if !state.capture_drag && buttons.get_pressed().next().is_some() {
+            let threshold = thresholds.get(captured).unwrap_or(&drag_threshold);
+            if threshold.is_exceeded(mouse_pos.distance(state.down_pos), time.elapsed_seconds() - state.drag_down_time) {
+                state.capture_drag = true;
+                state.drag_started = true;
+            }
+        }
+        let inside = query.get(captured).map(|(.., hitbox, _)| hitbox.contains(mouse_pos)).unwrap_or(false);
+        // Releases resolve to clicks unless dragged, like presses outside of pointer capture.
+        let click = !state.capture_drag && inside;
+        for (button, down, click_flag) in [
+            (MouseButton::Left, EventFlags::LeftDown, EventFlags::LeftClick),
+            (MouseButton::Right, EventFlags::RightDown, EventFlags::RightClick),
+            (MouseButton::Middle, EventFlags::MidDown, EventFlags::MidClick),
+        ] {
+            if buttons.just_pressed(button) {
+                entity.insert(CursorAction(down));
+            } else if buttons.just_released(button) && click {
+                entity.insert(CursorAction(click_flag));
+            }
+        }
+        if let Some(index) = find_other(buttons.get_just_pressed()) {
+            state.other_button = index;
+            entity.insert(CursorAction(EventFlags::OtherDown));
+        } else if let Some(index) = find_other(buttons.get_just_released()) {
+            state.other_button = index;
+            if click {
+                entity.insert(CursorAction(EventFlags::OtherClick));
+            }
+        }
+        // Captured presses are routed like drags once the drag threshold is exceeded, regardless of the hitbox.
+        let (left, right, mid, other) = match state.capture_drag {
+            true => (EventFlags::LeftDrag, EventFlags::RightDrag, EventFlags::MidDrag, EventFlags::OtherDrag),
+            false => (EventFlags::LeftPressed, EventFlags::RightPressed, EventFlags::MidPressed, EventFlags::OtherPressed),
+        };
+        entity.insert(CursorFocus(if buttons.pressed(MouseButton::Left) {
+            left
+        } else if buttons.pressed(MouseButton::Right) {
+            right
+        } else if buttons.pressed(MouseButton::Middle) {
+            mid
+        } else if find_other(buttons.get_pressed()).is_some() {
+            other
+        } else {
+            EventFlags::Hover
+        }));
+        // Implicitly released if all buttons are released.
+        if buttons.get_just_released().next().is_some() && buttons.get_pressed().next().is_none() {
+            if std::mem::take(&mut state.capture_drag) {
+                entity.insert(CursorAction(EventFlags::DragEnd));
+                iter(EventFlags::Drop)
+                    .filter(|(e, ..)| e != &captured)
+                    .filter(|(.., hitbox)| hitbox.contains(mouse_pos))
+                    .max_by(|(.., a), (.., b)| a.z().total_cmp(&b.z()))
+                    .filter(|(entity, ..)| accepts(captured, *entity))
+                    .exec_with(|(entity, ..)| commands.entity(entity).insert(CursorAction(EventFlags::Drop)).end());
+            }
+            state.release_pointer();
+        }
+        return;
+    }
     if state.dragging {
         state.caught = true;
+        if state.drag_pending && buttons.pressed(state.drag_button) {
+            let threshold = state.drag_target
+                .and_then(|entity| thresholds.get(entity).ok())
+                .unwrap_or(&drag_threshold);
+            if threshold.is_exceeded(mouse_pos.distance(state.down_pos), time.elapsed_seconds() - state.drag_down_time) {
+                state.drag_pending = false;
+                state.drag_started = true;
+            }
+        }
+        let drag_button = state.drag_button;
         if let Some(mut entity) = state.drag_target(&mut commands) {
             state.focused = Some(entity.id());
             if !buttons.pressed(state.drag_button) {
-                if state.drag_dbl_click && time.elapsed_seconds() - state.last_lmb_down_time[0] <= double_click.get() {
+                let is_double_click = state.drag_dbl_click 
+                    && time.elapsed_seconds() - state.last_lmb_down_time[0] <= double_click.get();
+                if is_double_click {
                     entity.insert(CursorAction(EventFlags::DoubleClick));
                     entity.insert(CursorFocus(EventFlags::Hover));
                     state.clear_dbl_click();
+                } else if state.drag_pending {
+                    // Released under the drag threshold, resolve to a click if inside the hitbox.
+                    let inside = query.get(entity.id())
+                        .map(|(.., hitbox, _)| hitbox.contains(mouse_pos))
+                        .unwrap_or(false);
+                    if inside {
+                        entity.insert(CursorAction(match state.drag_button {
+                            MouseButton::Left => EventFlags::LeftClick,
+                            MouseButton::Right => EventFlags::RightClick,
+                            MouseButton::Middle => EventFlags::MidClick,
+                            MouseButton::Other(_) => EventFlags::OtherClick,
+                        }));
+                        entity.insert(CursorFocus(EventFlags::Hover));
+                    }
                 } else {
                     entity.insert(CursorAction(EventFlags::DragEnd));
                     entity.insert(CursorFocus(EventFlags::Hover));
                 }
+                let was_pending = state.drag_pending;
                 state.dragging = false;
+                state.drag_pending = false;
                 state.drag_target = None;
                 let dragged_id = entity.id();
-                iter(EventFlags::Drop)
-                    .filter(|(.., hitbox)| hitbox.contains(mouse_pos))
-                    .max_by(|(.., a), (.., b)| a.z().total_cmp(&b.z()))
-                    .exec_with(|(entity, ..)| commands.entity(entity).insert(CursorAction(EventFlags::Drop)).end());
+                if !was_pending {
+                    iter(EventFlags::Drop)
+                        .filter(|(.., hitbox)| hitbox.contains(mouse_pos))
+                        .max_by(|(.., a), (.., b)| a.z().total_cmp(&b.z()))
+                        .filter(|(entity, ..)| accepts(dragged_id, *entity))
+                        .exec_with(|(entity, ..)| commands.entity(entity).insert(CursorAction(EventFlags::Drop)).end());
+                }
                 iter(EventFlags::ClickOutside)
                     .filter(|(e, ..)| e != &dragged_id)
                     .filter(|(.., hitbox)| !hitbox.contains(mouse_pos))
@@ -118,16 +285,25 @@ pub fn mouse_button_input(
                     entity.insert(CursorAction(EventFlags::RightDown));
                 } else if state.drag_button != MouseButton::Middle && buttons.just_pressed(MouseButton::Middle) {
                     entity.insert(CursorAction(EventFlags::MidDown));
+                } else if let Some(index) = find_other(buttons.get_just_pressed()
+                        .filter(|button| **button != drag_button)) {
+                    state.other_button = index;
+                    entity.insert(CursorAction(EventFlags::OtherDown));
                 }
-                entity.insert(CursorFocus(match state.drag_button {
-                    MouseButton::Left => EventFlags::LeftDrag,
-                    MouseButton::Right => EventFlags::RightDrag,
-                    MouseButton::Middle => EventFlags::MidDrag,
-                    MouseButton::Other(_) => EventFlags::LeftDrag,
+                entity.insert(CursorFocus(match (state.drag_button, state.drag_pending) {
+                    (MouseButton::Left, false) => EventFlags::LeftDrag,
+                    (MouseButton::Right, false) => EventFlags::RightDrag,
+                    (MouseButton::Middle, false) => EventFlags::MidDrag,
+                    (MouseButton::Other(_), false) => EventFlags::OtherDrag,
+                    (MouseButton::Left, true) => EventFlags::LeftPressed,
+                    (MouseButton::Right, true) => EventFlags::RightPressed,
+                    (MouseButton::Middle, true) => EventFlags::MidPressed,
+                    (MouseButton::Other(_), true) => EventFlags::OtherPressed,
                 }));
             }
         } else if !buttons.pressed(state.drag_button) {
             state.dragging = false;
+            state.drag_pending = false;
             state.drag_target = None;
             iter(EventFlags::ClickOutside)
             .filter(|(.., hitbox)| !hitbox.contains(mouse_pos))
@@ -148,11 +324,18 @@ pub fn mouse_button_input(
             if buttons.just_pressed(MouseButton::Left) {
                 commands.entity(entity).insert(CursorAction(EventFlags::LeftDown));
                 if flag.contains(EventFlags::LeftDrag) {
+                    let threshold = thresholds.get(entity).unwrap_or(&drag_threshold);
                     state.drag_target = Some(entity);
                     state.dragging = true;
+                    state.drag_pending = !threshold.is_exceeded(0.0, 0.0);
+                    state.drag_started = !state.drag_pending;
+                    state.drag_down_time = time.elapsed_seconds();
                     state.drag_button = MouseButton::Left;
                     state.drag_dbl_click = flag.contains(EventFlags::DoubleClick);
-                    commands.entity(entity).insert(CursorFocus(EventFlags::LeftDrag));
+                    commands.entity(entity).insert(CursorFocus(match state.drag_pending {
+                        true => EventFlags::LeftPressed,
+                        false => EventFlags::LeftDrag,
+                    }));
                     state.focused = Some(entity);
                 } else {
                     commands.entity(entity).insert(CursorFocus(EventFlags::LeftPressed));
@@ -177,6 +360,7 @@ pub fn mouse_button_input(
                 commands.entity(entity).insert(CursorAction(EventFlags::RightDown));
                 if flag.contains(EventFlags::RightDrag) {
                     state.drag_target = Some(entity);
+                    state.drag_started = true;
                     state.drag_button = MouseButton::Right;
                     state.drag_dbl_click = false;
                     commands.entity(entity).insert(CursorFocus(EventFlags::RightDrag));
@@ -205,6 +389,7 @@ pub fn mouse_button_input(
                 commands.entity(entity).insert(CursorAction(EventFlags::MidDown));
                 if flag.contains(EventFlags::MidDrag) {
                     state.drag_target = Some(entity);
+                    state.drag_started = true;
                     state.drag_button = MouseButton::Middle;
                     state.drag_dbl_click = false;
                     commands.entity(entity).insert(CursorFocus(EventFlags::MidDrag));
@@ -218,6 +403,43 @@ pub fn mouse_button_input(
                 state.focused = Some(entity);
             }
         }
+    } else if let Some(index) = find_other(buttons.get_pressed()) {
+        let button = MouseButton::Other(index);
+        if buttons.just_pressed(button) { 
+            state.down_pos = mouse_pos;
+            state.other_button = index;
+        }
+        if let Some((entity, flag)) = iter(EventFlags::OtherDrag|EventFlags::OtherClick)
+            .filter(|(.., hitbox)| hitbox.contains(mouse_pos))
+            .max_by(|(.., a), (.., b)| a.compare(b))
+            .map(|(entity, flags, _)| (entity, flags)
+        ) {
+            state.caught = true;
+            if buttons.just_pressed(button) {
+                commands.entity(entity).insert(CursorAction(EventFlags::OtherDown));
+                if flag.contains(EventFlags::OtherDrag) {
+                    let threshold = thresholds.get(entity).unwrap_or(&drag_threshold);
+                    state.drag_target = Some(entity);
+                    state.dragging = true;
+                    state.drag_pending = !threshold.is_exceeded(0.0, 0.0);
+                    state.drag_started = !state.drag_pending;
+                    state.drag_down_time = time.elapsed_seconds();
+                    state.drag_button = button;
+                    state.drag_dbl_click = false;
+                    commands.entity(entity).insert(CursorFocus(match state.drag_pending {
+                        true => EventFlags::OtherPressed,
+                        false => EventFlags::OtherDrag,
+                    }));
+                    state.focused = Some(entity);
+                } else {
+                    commands.entity(entity).insert(CursorFocus(EventFlags::OtherPressed));
+                    state.focused = Some(entity);
+                }
+            } else if flag.contains(EventFlags::OtherClick) {
+                commands.entity(entity).insert(CursorFocus(EventFlags::OtherPressed));
+                state.focused = Some(entity);
+            }
+        }
     } else {
         if buttons.just_released(MouseButton::Left) {
             let down = state.down_pos;
@@ -256,6 +478,17 @@ pub fn mouse_button_input(
             iter(EventFlags::ClickOutside)
                 .filter(|(.., hitbox)| !hitbox.contains(mouse_pos))
                 .for_each(|(entity, ..)| commands.entity(entity).insert(CursorClickOutside).end());
+        } else if let Some(index) = find_other(buttons.get_just_released()) {
+            let down = state.down_pos;
+            state.other_button = index;
+            iter(EventFlags::OtherClick)
+                .filter(|(.., hitbox)| hitbox.contains(mouse_pos) && hitbox.contains(down))
+                .max_by(|(.., a), (.., b)| a.compare(b))
+                .map(|(entity, ..)| commands.entity(entity).insert(CursorAction(EventFlags::OtherClick)).end())
+                .exec(|| state.caught = true);
+            iter(EventFlags::ClickOutside)
+                .filter(|(.., hitbox)| !hitbox.contains(mouse_pos))
+                .for_each(|(entity, ..)| commands.entity(entity).insert(CursorClickOutside).end());
         }
         if state.focused.is_none() {
             iter(EventFlags::Hover)
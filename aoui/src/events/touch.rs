@@ -0,0 +1,255 @@
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res, ResMut, Resource, Commands, Local};
+use bevy::ecs::query::{With, Without};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::input::{Input, mouse::MouseButton, touch::Touches};
+use bevy::math::Vec2;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+use bevy::render::camera::Camera;
+use bevy::transform::components::GlobalTransform;
+
+use crate::widgets::clipping::CameraClip;
+
+use super::{AouiCamera, ActiveDetection, CursorDetection, EventFlags, MouseWheelAction, ScrollScaling};
+
+/// Multi-touch gestures received by a widget this frame.
+///
+/// Sent to the topmost widget under the gesture listening to `Gesture`.
+/// Two finger pans are additionally sent as [`MouseWheelAction`]s
+/// to widgets listening to `MouseWheel`, i.e. `Scrolling`.
+#[derive(Debug, Clone, Copy, PartialEq, Component, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct GestureAction {
+    /// Ratio of the current and previous distance between two fingers, `1.0` if not pinching.
+    pub pinch: f32,
+    /// Movement of the center of two fingers in pixels, in bevy's coordinate system.
+    pub pan: Vec2,
+}
+
+impl Default for GestureAction {
+    fn default() -> Self {
+        Self {
+            pinch: 1.0,
+            pan: Vec2::ZERO,
+        }
+    }
+}
+
+/// Cursor state generated from touches.
+///
+/// A single finger acts as the left mouse button,
+/// placing a second finger cancels it until all fingers are lifted.
+///
+/// Holding a finger on a widget presses it, so long presses are
+/// sent as [`EvLongPress`](super::EvLongPress) with timing from [`HoldThreshold`](super::HoldThreshold).
+#[derive(Debug, Resource, Default)]
+pub struct TouchState {
+    primary: Option<u64>,
+    position: Option<Vec2>,
+    buttons: Input<MouseButton>,
+    active: bool,
+    cancelled: bool,
+    /// A tap pressed and released in the same frame, released on the next frame.
+    release_next: bool,
+    two_finger: Option<(f32, Vec2)>,
+    gesture: Option<(Vec2, GestureAction)>,
+}
+
+impl TouchState {
+    /// If touch is driving the cursor this frame, returns its window position and button state.
+    pub fn pointer(&self) -> Option<(Vec2, &Input<MouseButton>)> {
+        if self.active {
+            Some((self.position?, &self.buttons))
+        } else {
+            None
+        }
+    }
+
+    /// Returns true if any finger is on the screen.
+    pub fn is_touching(&self) -> bool {
+        self.primary.is_some() || self.cancelled
+    }
+}
+
+/// Convert [`Touches`] into cursor input and gestures.
+pub fn touch_input(
+    touches: Res<Touches>,
+    mut state: ResMut<TouchState>,
+) {
+    state.buttons.clear();
+    state.gesture = None;
+    if state.release_next {
+        state.release_next = false;
+        state.buttons.release(MouseButton::Left);
+        return;
+    }
+    let mut pressed: Vec<_> = touches.iter().collect();
+    pressed.sort_by_key(|x| x.id());
+    if let [a, b, ..] = pressed[..] {
+        if state.primary.take().is_some() {
+            state.buttons.reset_all();
+        }
+        state.cancelled = true;
+        state.active = false;
+        let center = (a.position() + b.position()) / 2.0;
+        let distance = a.position().distance(b.position());
+        if let Some((last_distance, last_center)) = state.two_finger {
+            let delta = center - last_center;
+            state.gesture = Some((center, GestureAction {
+                pinch: if last_distance > 0.0 { distance / last_distance } else { 1.0 },
+                pan: Vec2::new(delta.x, -delta.y),
+            }));
+        }
+        state.two_finger = Some((distance, center));
+        return;
+    }
+    state.two_finger = None;
+    if pressed.is_empty() {
+        state.cancelled = false;
+    }
+    if state.cancelled {
+        state.active = false;
+        return;
+    }
+    match state.primary {
+        Some(id) => if let Some(touch) = touches.get_pressed(id) {
+            state.position = Some(touch.position());
+        } else {
+            if let Some(touch) = touches.iter_just_released().find(|x| x.id() == id) {
+                state.position = Some(touch.position());
+                state.buttons.release(MouseButton::Left);
+            } else {
+                // Cancelled by the system, do not click.
+                state.buttons.reset_all();
+                state.active = false;
+            }
+            state.primary = None;
+        },
+        None => match touches.iter_just_pressed().next() {
+            Some(touch) => {
+                state.position = Some(touch.position());
+                state.buttons.press(MouseButton::Left);
+                state.active = true;
+                if let Some(released) = touches.get_released(touch.id()) {
+                    // Pressed and released in the same frame, click on the next frame.
+                    state.position = Some(released.position());
+                    state.release_next = true;
+                } else {
+                    state.primary = Some(touch.id());
+                }
+            },
+            None => state.active = false,
+        },
+    }
+}
+
+/// Send [`GestureAction`] and [`MouseWheelAction`] generated by touches.
+pub fn touch_gesture_event(
+    mut commands: Commands,
+    state: Res<TouchState>,
+    scaling: Res<ScrollScaling>,
+    marked_camera: Query<(&Camera, &GlobalTransform), With<AouiCamera>>,
+    unmarked_camera: Query<(&Camera, &GlobalTransform), (Without<AouiCamera>, Without<CameraClip>)>,
+    query: Query<(Entity, &EventFlags, ActiveDetection, CursorDetection)>,
+    mut lines: Local<Vec2>,
+) {
+    let Some((position, gesture)) = state.gesture else {
+        *lines = Vec2::ZERO;
+        return;
+    };
+    let(camera, camera_transform) = match marked_camera.get_single() {
+        Ok((cam, transform)) => (cam, transform),
+        Err(_) => match unmarked_camera.get_single(){
+            Ok((cam, transform)) => (cam, transform),
+            Err(_) => return,
+        },
+    };
+    let Some(position) = camera.viewport_to_world(camera_transform, position)
+        .map(|ray| ray.origin.truncate()) else {return;};
+    let topmost = |flag: EventFlags| query.iter()
+        .filter(|(_, flags, active, hitbox)| flags.contains(flag) && active.is_active() && hitbox.contains(position))
+        .max_by(|(.., a), (.., b)| a.compare(b))
+        .map(|(entity, ..)| entity);
+    if let Some(entity) = topmost(EventFlags::Gesture) {
+        commands.entity(entity).insert(gesture);
+    }
+    if gesture.pan != Vec2::ZERO {
+        if let Some(entity) = topmost(EventFlags::MouseWheel) {
+            *lines += gesture.pan;
+            let count = (*lines / scaling.line_to_pixels).as_ivec2();
+            *lines %= scaling.line_to_pixels;
+            commands.entity(entity).insert(MouseWheelAction {
+                lines: count,
+                pixels: gesture.pan,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy::app::{App, PreUpdate};
+    use bevy::ecs::schedule::IntoSystemConfigs;
+    use bevy::input::{mouse::MouseButton, touch::{Touches, TouchInput, TouchPhase, touch_screen_input_system}};
+    use bevy::math::Vec2;
+
+    use super::{TouchState, touch_input};
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_event::<TouchInput>()
+            .init_resource::<Touches>()
+            .init_resource::<TouchState>()
+            .add_systems(PreUpdate, (touch_screen_input_system, touch_input).chain());
+        app
+    }
+
+    fn touch(app: &mut App, id: u64, phase: TouchPhase, position: Vec2) {
+        app.world.send_event(TouchInput { phase, position, force: None, id });
+    }
+
+    fn left(app: &App) -> (bool, bool, bool) {
+        let state = app.world.resource::<TouchState>();
+        let (_, buttons) = state.pointer().expect("touch should drive the cursor");
+        (buttons.pressed(MouseButton::Left), buttons.just_pressed(MouseButton::Left), buttons.just_released(MouseButton::Left))
+    }
+
+    #[test]
+    fn tap() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::new(10.0, 10.0));
+        app.update();
+        assert_eq!(left(&app), (true, true, false));
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::new(10.0, 10.0));
+        app.update();
+        assert_eq!(left(&app), (false, false, true));
+        app.update();
+        assert!(app.world.resource::<TouchState>().pointer().is_none());
+    }
+
+    #[test]
+    fn same_frame_tap() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::new(10.0, 10.0));
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::new(10.0, 10.0));
+        app.update();
+        assert_eq!(left(&app), (true, true, false));
+        app.update();
+        assert_eq!(left(&app), (false, false, true));
+        assert!(!app.world.resource::<TouchState>().is_touching());
+    }
+
+    #[test]
+    fn second_finger_cancels() {
+        let mut app = app();
+        touch(&mut app, 0, TouchPhase::Started, Vec2::new(10.0, 10.0));
+        app.update();
+        touch(&mut app, 1, TouchPhase::Started, Vec2::new(50.0, 10.0));
+        app.update();
+        assert!(app.world.resource::<TouchState>().pointer().is_none());
+        touch(&mut app, 0, TouchPhase::Ended, Vec2::new(10.0, 10.0));
+        app.update();
+        assert!(app.world.resource::<TouchState>().pointer().is_none());
+    }
+}
@@ -1,9 +1,11 @@
 use bevy::{input::mouse::{MouseWheel, MouseScrollUnit}, math::{Vec2, IVec2}, window::{Window, PrimaryWindow}, render::camera::Camera, transform::components::GlobalTransform, ecs::{component::Component, system::{Resource, Local, Res}}};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 use bevy::ecs::{system::{Query, Commands}, event::EventReader, query::{With, Without}, entity::Entity};
 
 use crate::widgets::clipping::CameraClip;
 
-use super::{EventFlags, AouiCamera, CursorDetection, ActiveDetection};
+use super::{EventFlags, AouiCamera, CursorDetection, ActiveDetection, CursorState, InputPlayer};
 
 
 
@@ -27,7 +29,8 @@ impl Default for ScrollScaling {
 
 /// This is relatively independent, as the mousewheel action does not take
 /// the drag target and the cursor action target into account.
-#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 #[component(storage="SparseSet")]
 pub struct MouseWheelAction{
     pub lines: IVec2,
@@ -43,6 +46,7 @@ impl MouseWheelAction {
 
 pub fn mousewheel_event(
     mut commands: Commands,
+    state: Res<CursorState>,
     scaling: Res<ScrollScaling>,
     windows: Query<&Window, With<PrimaryWindow>>,
     marked_camera: Query<(&Camera, &GlobalTransform), With<AouiCamera>>,
@@ -50,22 +54,32 @@ pub fn mousewheel_event(
     query: Query<(Entity, &EventFlags, ActiveDetection, CursorDetection)>,
     mut lines: Local<Vec2>,
     mut reader: EventReader<MouseWheel>,
+    player: Option<Res<InputPlayer>>,
 ) {
-    let(camera, camera_transform) = match marked_camera.get_single() {
-        Ok((cam, transform)) => (cam, transform),
-        Err(_) => match unmarked_camera.get_single(){
-            Ok((cam, transform)) => (cam, transform),
-            Err(_) => return,
-        },
+    let mouse_pos = match player.and_then(|x| x.cursor_position()) {
+        // Recorded positions are in world space.
+        Some(position) => position,
+        None => {
+            let(camera, camera_transform) = match marked_camera.get_single() {
+                Ok((cam, transform)) => (cam, transform),
+                Err(_) => match unmarked_camera.get_single(){
+                    Ok((cam, transform)) => (cam, transform),
+                    Err(_) => return,
+                },
+            };
+            let Ok(window) = windows.get_single() else { return };       
+            let Some(mouse_pos) = window.cursor_position()
+                .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
+                .map(|ray| ray.origin.truncate()) else {return;};
+            mouse_pos
+        }
     };
-    let Ok(window) = windows.get_single() else { return };       
-    let Some(mouse_pos) = window.cursor_position()
-        .and_then(|cursor| camera.viewport_to_world(camera_transform, cursor))
-        .map(|ray| ray.origin.truncate()) else {return;};
-    if let Some(entity) = query.iter()
+    // Captured entities receive mouse wheel regardless of hit testing.
+    let captured = state.pointer_capture().filter(|entity| commands.get_entity(*entity).is_some());
+    if let Some(entity) = captured.or_else(|| query.iter()
         .filter(|(_, flags, active, hitbox)| flags.contains(EventFlags::MouseWheel) && active.is_active() && hitbox.contains(mouse_pos))
         .max_by(|(.., a), (.., b)| a.compare(b))
-        .map(|(entity,..)| entity) {
+        .map(|(entity,..)| entity)) {
         
         let mut count = 0;
         for event in reader.read() {
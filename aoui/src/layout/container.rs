@@ -2,13 +2,14 @@ use std::ops::Range;
 
 use bevy::prelude::*;
 
-use crate::{Size2, layout::Layout};
+use crate::{Size2, layout::{Layout, BuiltinLayout, BoundsLayout}};
 
 /// A configurable container that lays out a sequence of Entities.
-#[derive(Debug, Component)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct Container {
-    /// Layout of the container.
-    pub layout: Box<dyn Layout>,
+    /// Layout of the container, converts from any `Box<dyn Layout>` with `into`.
+    pub layout: LayoutObject,
     /// Margin between cells, always corresponds to the X and Y axis
     /// regardless of layout directions.
     pub margin: Size2,
@@ -30,11 +31,76 @@ impl std::ops::Deref for Container {
     type Target = dyn Layout;
 
     fn deref(&self) -> &Self::Target {
-        self.layout.as_ref()
+        self.layout.as_layout()
+    }
+}
+
+/// Layout of a [`Container`].
+///
+/// Built-in layouts are stored as a [`BuiltinLayout`] and can be reflected and serialized,
+/// custom layouts are opaque to reflection.
+#[derive(Debug, Reflect)]
+pub enum LayoutObject {
+    Builtin(BuiltinLayout),
+    Custom(#[reflect(ignore, default = "default_layout")] Box<dyn Layout>),
+}
+
+fn default_layout() -> Box<dyn Layout> {
+    Box::new(BoundsLayout::default())
+}
+
+impl Default for LayoutObject {
+    fn default() -> Self {
+        Self::Builtin(BuiltinLayout::Bounds(BoundsLayout::default()))
+    }
+}
+
+impl LayoutObject {
+    /// Create a layout object, built-in layouts are stored as [`BuiltinLayout`].
+    pub fn new(layout: impl Layout) -> Self {
+        Self::from(Box::new(layout) as Box<dyn Layout>)
+    }
+
+    pub fn as_layout(&self) -> &dyn Layout {
+        match self {
+            LayoutObject::Builtin(layout) => layout.as_layout(),
+            LayoutObject::Custom(layout) => layout.as_ref(),
+        }
+    }
+
+    pub fn as_layout_mut(&mut self) -> &mut dyn Layout {
+        match self {
+            LayoutObject::Builtin(layout) => layout.as_layout_mut(),
+            LayoutObject::Custom(layout) => layout.as_mut(),
+        }
+    }
+
+    pub fn downcast_ref<T: Layout>(&self) -> Option<&T> {
+        self.as_layout().downcast_ref()
+    }
+
+    pub fn downcast_mut<T: Layout>(&mut self) -> Option<&mut T> {
+        self.as_layout_mut().downcast_mut()
+    }
+}
+
+impl From<Box<dyn Layout>> for LayoutObject {
+    fn from(layout: Box<dyn Layout>) -> Self {
+        match BuiltinLayout::from_layout(layout.as_ref()) {
+            Some(builtin) => Self::Builtin(builtin),
+            None => Self::Custom(layout),
+        }
+    }
+}
+
+impl From<BuiltinLayout> for LayoutObject {
+    fn from(layout: BuiltinLayout) -> Self {
+        Self::Builtin(layout)
     }
 }
 
 #[derive(Debug, Clone, Copy, Component, Default, Reflect, PartialEq, Eq)]
+#[reflect(Component, Default)]
 #[non_exhaustive]
 /// Cause special behaviors when inserted into a [`Container`].
 pub enum LayoutControl {
@@ -49,6 +49,7 @@ impl LayoutOutput {
 /// 
 /// This layout usually should contain only one child with no offset.
 #[derive(Debug, Clone, Copy, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BoundsLayout {
     /// If set, use `Dimension` on that axis.
     pub fixed: [bool; 2],
@@ -137,6 +138,7 @@ impl Layout for BoundsLayout {
 
 /// A size agnostic mono-directional container.
 #[derive(Debug, Clone, Copy, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StackLayout {
     pub direction: LayoutDir,
 }
@@ -148,6 +150,7 @@ impl StackLayout {
 
 /// A fix-sized mono-directional container.
 #[derive(Debug, Clone, Copy, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpanLayout {
     /// The axis, horizontal or vertical.
     pub direction: LayoutDir,
@@ -172,6 +175,7 @@ impl SpanLayout {
 ///
 /// * When supplied a [`Anchor::Custom`](bevy::sprite::Anchor) Anchor.
 #[derive(Debug, Clone, Copy, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParagraphLayout {
     /// The primary axis, horizontal or vertical
     pub direction: LayoutDir,
@@ -197,6 +201,7 @@ impl Default for ParagraphLayout {
 ///
 /// * If `row_dir` is not orthogonal to `column_dir`.
 #[derive(Debug, Clone, Copy, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SizedGridLayout {
     /// Determines the size of a cell.
     pub cell_size: Size2,
@@ -218,6 +223,7 @@ pub struct SizedGridLayout {
 ///
 /// * If `row_dir` is not orthogonal to `column_dir`.
 #[derive(Debug, Clone, Copy, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedGridLayout {
     /// Determines the number of cells
     pub cells: UVec2,
@@ -237,6 +243,7 @@ pub struct FixedGridLayout {
 ///
 /// * If `row_dir` is not orthogonal to `column_dir`.
 #[derive(Debug, Clone, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicTableLayout {
     /// Determines the number of columns, use a large number for infinite.
     pub columns: usize,
@@ -254,6 +261,7 @@ pub struct DynamicTableLayout {
 ///
 /// * If `row_dir` is not orthogonal to `column_dir`.
 #[derive(Debug, Clone, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableLayout {
     /// Determines the number and size of columns
     pub columns: Vec<(SizeUnit, f32)>,
@@ -274,4 +282,84 @@ impl TableLayout {
             stretch: false,
         }
     }
-}
\ No newline at end of file
+}
+
+/// One of the built-in layouts, as a concrete serializable type.
+#[derive(Debug, Clone, bevy::prelude::Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BuiltinLayout {
+    Bounds(BoundsLayout),
+    Stack(StackLayout),
+    Span(SpanLayout),
+    Paragraph(ParagraphLayout),
+    SizedGrid(SizedGridLayout),
+    FixedGrid(FixedGridLayout),
+    DynamicTable(DynamicTableLayout),
+    Table(TableLayout),
+}
+
+impl BuiltinLayout {
+    /// Convert into a layout object.
+    pub fn into_layout(self) -> Box<dyn Layout> {
+        match self {
+            BuiltinLayout::Bounds(x) => Box::new(x),
+            BuiltinLayout::Stack(x) => Box::new(x),
+            BuiltinLayout::Span(x) => Box::new(x),
+            BuiltinLayout::Paragraph(x) => Box::new(x),
+            BuiltinLayout::SizedGrid(x) => Box::new(x),
+            BuiltinLayout::FixedGrid(x) => Box::new(x),
+            BuiltinLayout::DynamicTable(x) => Box::new(x),
+            BuiltinLayout::Table(x) => Box::new(x),
+        }
+    }
+
+    /// Obtain a reference to the inner layout.
+    pub fn as_layout(&self) -> &dyn Layout {
+        match self {
+            BuiltinLayout::Bounds(x) => x,
+            BuiltinLayout::Stack(x) => x,
+            BuiltinLayout::Span(x) => x,
+            BuiltinLayout::Paragraph(x) => x,
+            BuiltinLayout::SizedGrid(x) => x,
+            BuiltinLayout::FixedGrid(x) => x,
+            BuiltinLayout::DynamicTable(x) => x,
+            BuiltinLayout::Table(x) => x,
+        }
+    }
+
+    /// Obtain a mutable reference to the inner layout.
+    pub fn as_layout_mut(&mut self) -> &mut dyn Layout {
+        match self {
+            BuiltinLayout::Bounds(x) => x,
+            BuiltinLayout::Stack(x) => x,
+            BuiltinLayout::Span(x) => x,
+            BuiltinLayout::Paragraph(x) => x,
+            BuiltinLayout::SizedGrid(x) => x,
+            BuiltinLayout::FixedGrid(x) => x,
+            BuiltinLayout::DynamicTable(x) => x,
+            BuiltinLayout::Table(x) => x,
+        }
+    }
+
+    /// Try obtain a built-in layout from a layout object.
+    pub fn from_layout(layout: &dyn Layout) -> Option<Self> {
+        macro_rules! downcast {
+            ($($variant: ident: $ty: ty),*) => {
+                $(if let Some(x) = layout.downcast_ref::<$ty>() {
+                    return Some(BuiltinLayout::$variant(x.clone()));
+                })*
+            };
+        }
+        downcast!(
+            Bounds: BoundsLayout,
+            Stack: StackLayout,
+            Span: SpanLayout,
+            Paragraph: ParagraphLayout,
+            SizedGrid: SizedGridLayout,
+            FixedGrid: FixedGridLayout,
+            DynamicTable: DynamicTableLayout,
+            Table: TableLayout
+        );
+        None
+    }
+}
@@ -6,6 +6,7 @@ use crate::{layout::LayoutControl, Anchor};
 
 /// Horizontal or Vertical.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Axis {
     #[default]
     Horizontal, Vertical,
@@ -23,6 +24,7 @@ impl From<bool> for Axis {
 
 /// Order items are laid out in a [`Container`](crate::layout::Container).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LayoutDir {
     LeftToRight, RightToLeft,
     BottomToTop, TopToBottom,
@@ -76,6 +78,7 @@ impl From<&LayoutDir> for Axis {
 
 /// Where items are aligned to in a [`Container`](crate::layout::Container).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature="serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alignment {
     Center, Bottom, Top, Left, Right
 }
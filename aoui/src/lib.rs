@@ -281,6 +281,8 @@ pub mod events;
 pub mod anim;
 
 pub mod signals;
+#[cfg(feature="scene")]
+pub mod scene;
 pub use core::*;
 
 #[doc(hidden)]
@@ -305,5 +307,7 @@ impl bevy::prelude::Plugin for AouiPlugin {
             .add_plugins(anim::AnimationPlugin)
             .add_plugins(widgets::WidgetsPlugin)
         ;
+        #[cfg(feature="scene")]
+        app.add_plugins(scene::ScenePlugin);
     }
 }
\ No newline at end of file
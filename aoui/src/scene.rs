@@ -0,0 +1,398 @@
+//! A serializable UI scene format, loaded through the `AssetServer`.
+//!
+//! Scenes are `json` files with the extension `.aoui.json`, describing a tree of widgets.
+//!
+//! ```json
+//! {
+//!     "nodes": [{
+//!         "anchor": [-0.5, 0.5],
+//!         "dimension": [["Pixels", 200], ["Pixels", 100]],
+//!         "layout": { "Stack": { "direction": "TopToBottom" } },
+//!         "children": [
+//!             { "sprite": "cat.png", "event": ["LeftClick"] },
+//!             { "text": "Hello, World!", "color": { "Rgba": { "red": 1, "green": 1, "blue": 1, "alpha": 1 } } }
+//!         ]
+//!     }]
+//! }
+//! ```
+//!
+//! Spawn a scene with [`AouiCommands::spawn_scene`], the spawned tree is
+//! rebuilt when the file changes, if `AssetPlugin` has file watching enabled.
+//!
+//! Event handlers are referenced by names registered in
+//! [`HandlerRegistry`](crate::events::HandlerRegistry), i.e.
+//! `"handlers": { "LeftClick": ["open_menu"] }`. The corresponding `event` flag is still required.
+//!
+//! A node is spawned as a
+//! * `sprite` if `sprite` is set.
+//! * `text` if `text` is set.
+//! * `rectangle` if `color` is set.
+//! * `frame` otherwise.
+
+use std::collections::BTreeMap;
+
+use bevy::asset::{Asset, AssetEvent, AssetId, AssetLoader, Assets, AsyncReadExt, Handle, LoadContext};
+use bevy::asset::io::Reader;
+use bevy::ecs::{component::Component, entity::Entity, event::EventReader, system::{Query, Res}};
+use bevy::hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy::core::Name;
+use bevy::math::Vec2;
+use bevy::reflect::TypePath;
+use bevy::render::color::Color;
+use bevy::utils::{BoxedFuture, HashSet};
+use serde::{Serialize, Deserialize};
+
+use crate::{Anchor, Size2, FontSize, ZIndex, Opacity};
+use crate::dsl::{AouiCommands, OneOrTwo, HandleOrString};
+use crate::dsl::builders::{FrameBuilder, SpriteBuilder, TextBuilder, RectangleBuilder};
+use crate::events::*;
+use crate::layout::BuiltinLayout;
+
+/// A tree of widgets, loaded from a `.aoui.json` file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Asset, TypePath)]
+pub struct UiScene {
+    /// Root nodes of the scene.
+    pub nodes: Vec<SceneNode>,
+}
+
+/// A widget in a [`UiScene`].
+///
+/// Fields mirror the `frame!` dsl, unspecified fields use their default values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SceneNode {
+    /// Inserted as `Name`.
+    pub name: Option<String>,
+    pub anchor: Anchor,
+    pub parent_anchor: Option<Anchor>,
+    pub center: Option<Anchor>,
+    pub offset: Size2,
+    pub rotation: f32,
+    pub scale: Vec2,
+    pub skew: Vec2,
+    pub z: f32,
+    pub z_index: Option<ZIndex>,
+    pub opacity: f32,
+    pub visible: Option<bool>,
+    pub clipping: Option<bool>,
+    pub dimension: Option<Size2>,
+    pub font_size: FontSize,
+    /// Names of [`EventFlags`], i.e. `["Hover", "LeftClick"]`.
+    pub event: Vec<String>,
+    pub layout: Option<BuiltinLayout>,
+    pub margin: Size2,
+    pub padding: Size2,
+    /// Path to an image, spawns a sprite.
+    pub sprite: Option<String>,
+    /// Spawns a text.
+    pub text: Option<String>,
+    /// Path to a font.
+    pub font: Option<String>,
+    /// Color of the sprite, text or rectangle.
+    pub color: Option<Color>,
+    /// Size of the sprite or rectangle.
+    pub size: Option<Vec2>,
+    /// Event name to names of handlers in [`HandlerRegistry`](crate::events::HandlerRegistry).
+    pub handlers: BTreeMap<String, Vec<String>>,
+    pub children: Vec<SceneNode>,
+}
+
+impl Default for SceneNode {
+    fn default() -> Self {
+        Self {
+            name: None,
+            anchor: Anchor::Center,
+            parent_anchor: None,
+            center: None,
+            offset: Size2::ZERO,
+            rotation: 0.0,
+            scale: Vec2::ONE,
+            skew: Vec2::ZERO,
+            z: 0.0,
+            z_index: None,
+            opacity: 1.0,
+            visible: None,
+            clipping: None,
+            dimension: None,
+            font_size: FontSize::None,
+            event: Vec::new(),
+            layout: None,
+            margin: Size2::ZERO,
+            padding: Size2::ZERO,
+            sprite: None,
+            text: None,
+            font: None,
+            color: None,
+            size: None,
+            handlers: BTreeMap::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Parse the name of a single [`EventFlags`].
+pub fn parse_event_flag(name: &str) -> Option<EventFlags> {
+    Some(match name {
+        "Idle" => EventFlags::Idle,
+        "Hover" => EventFlags::Hover,
+        "LeftDrag" => EventFlags::LeftDrag,
+        "LeftDown" => EventFlags::LeftDown,
+        "LeftPressed" => EventFlags::LeftPressed,
+        "LeftClick" => EventFlags::LeftClick,
+        "DoubleClick" => EventFlags::DoubleClick,
+        "MidDown" => EventFlags::MidDown,
+        "MidPressed" => EventFlags::MidPressed,
+        "MidClick" => EventFlags::MidClick,
+        "MidDrag" => EventFlags::MidDrag,
+        "RightDown" => EventFlags::RightDown,
+        "RightPressed" => EventFlags::RightPressed,
+        "RightClick" => EventFlags::RightClick,
+        "RightDrag" => EventFlags::RightDrag,
+        "OtherDown" => EventFlags::OtherDown,
+        "OtherPressed" => EventFlags::OtherPressed,
+        "OtherClick" => EventFlags::OtherClick,
+        "OtherDrag" => EventFlags::OtherDrag,
+        "Drop" => EventFlags::Drop,
+        "DragEnd" => EventFlags::DragEnd,
+        "ClickOutside" => EventFlags::ClickOutside,
+        "MouseWheel" => EventFlags::MouseWheel,
+        "Gesture" => EventFlags::Gesture,
+        "BlockAll" => EventFlags::BlockAll,
+        "All" => EventFlags::All,
+        _ => return None,
+    })
+}
+
+/// Insert `Handlers` referencing [`HandlerRegistry`](crate::events::HandlerRegistry) by event name.
+fn insert_named_handlers(commands: &mut AouiCommands, entity: Entity, event: &str, names: &[String]) {
+    macro_rules! insert {
+        ($($name: literal => $ty: ty),* $(,)?) => {
+            match event {
+                $($name => {
+                    let handlers = names.iter().fold(Handlers::<$ty>::new_empty(), |h, name| h.and(Handler::named(name.clone())));
+                    commands.entity(entity).insert(handlers);
+                },)*
+                _ => bevy::log::warn!("Unknown event \"{}\" in aoui scene.", event),
+            }
+        };
+    }
+    insert!(
+        "Hover" => EvHover,
+        "LeftDown" => EvLeftDown,
+        "LeftPressed" => EvLeftPressed,
+        "LeftClick" => EvLeftClick,
+        "LeftDrag" => EvLeftDrag,
+        "DoubleClick" => EvDoubleClick,
+        "MidDown" => EvMidDown,
+        "MidPressed" => EvMidPressed,
+        "MidClick" => EvMidClick,
+        "MidDrag" => EvMidDrag,
+        "RightDown" => EvRightDown,
+        "RightPressed" => EvRightPressed,
+        "RightClick" => EvRightClick,
+        "RightDrag" => EvRightDrag,
+        "OtherDown" => EvOtherDown,
+        "OtherPressed" => EvOtherPressed,
+        "OtherClick" => EvOtherClick,
+        "OtherDrag" => EvOtherDrag,
+        "DragEnd" => EvDragEnd,
+        "ClickOutside" => EvClickOutside,
+        "MouseWheel" => EvMouseWheel,
+        "Gesture" => EvGesture,
+        "ObtainFocus" => EvObtainFocus,
+        "LoseFocus" => EvLoseFocus,
+        "ObtainKeyboardFocus" => EvObtainKeyboardFocus,
+        "LoseKeyboardFocus" => EvLoseKeyboardFocus,
+        "LongPress" => EvLongPress,
+        "HoldRepeat" => EvHoldRepeat,
+        "HoldProgress" => EvHoldProgress,
+        "DragEnter" => EvDragEnter,
+        "DragLeave" => EvDragLeave,
+        "DropReceived" => EvDropReceived,
+        "PointerCaptureLost" => EvPointerCaptureLost,
+        "PageChanged" => EvPageChanged,
+    );
+}
+
+macro_rules! from_node {
+    ($node: expr, $ty: ident {$($field: ident: $value: expr),* $(,)?}) => {
+        $ty {
+            anchor: $node.anchor,
+            parent_anchor: $node.parent_anchor,
+            center: $node.center,
+            offset: $node.offset,
+            rotation: $node.rotation,
+            scale: Some(OneOrTwo($node.scale)),
+            skew: $node.skew,
+            z: $node.z,
+            z_index: $node.z_index,
+            opacity: Opacity::new($node.opacity),
+            visible: $node.visible,
+            clipping: $node.clipping,
+            dimension: $node.dimension,
+            font_size: $node.font_size,
+            event: $node.event_flags(),
+            layout: $node.layout.clone().map(BuiltinLayout::into_layout),
+            margin: OneOrTwo($node.margin),
+            padding: OneOrTwo($node.padding),
+            $($field: $value,)*
+            ..Default::default()
+        }
+    };
+}
+
+impl SceneNode {
+    /// Combined [`EventFlags`] of this node, unknown names are ignored with a warning.
+    pub fn event_flags(&self) -> Option<EventFlags> {
+        if self.event.is_empty() {
+            return None;
+        }
+        Some(self.event.iter().fold(EventFlags::Idle, |flags, name| {
+            match parse_event_flag(name) {
+                Some(flag) => flags | flag,
+                None => {
+                    bevy::log::warn!("Unknown event flag \"{}\" in aoui scene.", name);
+                    flags
+                }
+            }
+        }))
+    }
+
+    /// Spawn this node and its children.
+    pub fn spawn(&self, commands: &mut AouiCommands) -> Entity {
+        let children: Vec<Entity> = self.children.iter()
+            .map(|child| child.spawn(commands))
+            .collect();
+        let entity = if let Some(sprite) = &self.sprite {
+            commands.spawn_aoui(from_node!(self, SpriteBuilder {
+                sprite: HandleOrString::String(sprite.clone()),
+                color: self.color,
+                size: self.size,
+            }), (), children)
+        } else if let Some(text) = &self.text {
+            commands.spawn_aoui(from_node!(self, TextBuilder {
+                text: text.clone(),
+                font: self.font.clone().map(HandleOrString::String).unwrap_or_default(),
+                color: self.color,
+            }), (), children)
+        } else if self.color.is_some() {
+            commands.spawn_aoui(from_node!(self, RectangleBuilder {
+                color: self.color,
+                size: self.size,
+            }), (), children)
+        } else {
+            commands.spawn_aoui(from_node!(self, FrameBuilder {}), (), children)
+        };
+        if let Some(name) = &self.name {
+            commands.entity(entity).insert(Name::new(name.clone()));
+        }
+        for (event, names) in &self.handlers {
+            insert_named_handlers(commands, entity, event, names);
+        }
+        entity
+    }
+}
+
+impl UiScene {
+    /// Spawn root nodes of the scene.
+    pub fn spawn(&self, commands: &mut AouiCommands) -> Vec<Entity> {
+        self.nodes.iter().map(|node| node.spawn(commands)).collect()
+    }
+}
+
+/// Error loading a [`UiScene`].
+#[derive(Debug, thiserror::Error)]
+pub enum UiSceneError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Loads [`UiScene`]s from `.aoui.json` files.
+#[derive(Debug, Default)]
+pub struct UiSceneLoader;
+
+impl AssetLoader for UiSceneLoader {
+    type Asset = UiScene;
+    type Settings = ();
+    type Error = UiSceneError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(serde_json::from_slice(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["aoui.json"]
+    }
+}
+
+/// Root of a spawned [`UiScene`], its children are owned by the scene
+/// and are respawned when the scene is modified.
+#[derive(Debug, Clone, Component)]
+pub struct UiSceneRoot {
+    pub scene: Handle<UiScene>,
+    spawned: bool,
+}
+
+impl UiSceneRoot {
+    pub fn new(scene: Handle<UiScene>) -> Self {
+        Self { scene, spawned: false }
+    }
+}
+
+impl AouiCommands<'_, '_> {
+    /// Spawn a full sized frame containing a [`UiScene`].
+    pub fn spawn_scene(&mut self, scene: Handle<UiScene>) -> Entity {
+        self.spawn_aoui(FrameBuilder {
+            dimension: Some(Size2::FULL),
+            ..Default::default()
+        }, UiSceneRoot::new(scene), [])
+    }
+}
+
+/// Spawn loaded scenes and respawn modified ones.
+pub fn spawn_ui_scenes(
+    mut commands: AouiCommands,
+    scenes: Res<Assets<UiScene>>,
+    mut events: EventReader<AssetEvent<UiScene>>,
+    mut query: Query<(Entity, &mut UiSceneRoot)>,
+) {
+    let modified: HashSet<AssetId<UiScene>> = events.read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    for (entity, mut root) in query.iter_mut() {
+        if root.spawned && !modified.contains(&root.scene.id()) {
+            continue;
+        }
+        let Some(scene) = scenes.get(&root.scene) else { continue };
+        root.spawned = true;
+        let children = scene.spawn(&mut commands);
+        commands.entity(entity)
+            .despawn_descendants()
+            .push_children(&children);
+    }
+}
+
+pub(crate) struct ScenePlugin;
+
+impl bevy::app::Plugin for ScenePlugin {
+    fn build(&self, app: &mut bevy::app::App) {
+        use bevy::asset::AssetApp;
+        app.init_asset::<UiScene>()
+            .init_asset_loader::<UiSceneLoader>()
+            .add_systems(bevy::app::Update, spawn_ui_scenes);
+    }
+}
@@ -6,7 +6,11 @@ use bevy::transform::systems::{propagate_transforms, sync_simple_transforms};
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
 
-use crate::AouiREM;
+use crate::{AouiREM, DepthBudget, Anchor, Size2, FontSize};
+use crate::{Transform2D, BuildTransform, BuildMeshTransform, Dimension, DimensionData, DimensionSize};
+use crate::{ZIndex, Hitbox, HitboxShape, RotatedRect, Opacity, IgnoreAlpha, Clipping};
+use crate::layout::{Container, LayoutObject, BuiltinLayout, LayoutControl};
+use crate::core::depth::sequential_depth;
 
 use crate::core::pipeline::{compute_aoui_transforms, TRoot, TAll};
 use crate::core::systems::*;
@@ -56,6 +60,29 @@ impl bevy::prelude::Plugin for CorePlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
         app
             .init_resource::<AouiREM>()
+            .init_resource::<DepthBudget>()
+            .register_type::<Anchor>()
+            .register_type::<Size2>()
+            .register_type::<FontSize>()
+            .register_type::<DimensionSize>()
+            .register_type::<HitboxShape>()
+            .register_type::<Transform2D>()
+            .register_type::<BuildTransform>()
+            .register_type::<BuildMeshTransform>()
+            .register_type::<Dimension>()
+            .register_type::<DimensionData>()
+            .register_type::<ZIndex>()
+            .register_type::<Hitbox>()
+            .register_type::<RotatedRect>()
+            .register_type::<Opacity>()
+            .register_type::<IgnoreAlpha>()
+            .register_type::<Clipping>()
+            .register_type::<OptOutTextBoundsSync>()
+            .register_type::<OptOutFontSizeSync>()
+            .register_type::<BuiltinLayout>()
+            .register_type::<LayoutObject>()
+            .register_type::<Container>()
+            .register_type::<LayoutControl>()
             .configure_sets(PreUpdate, AouiEventSet.after(InputSystem))
             .add_systems(PreUpdate, bevy::ecs::prelude::apply_deferred
                 .after(AouiEventSet)
@@ -84,10 +111,12 @@ impl bevy::prelude::Plugin for CorePlugin {
                 copy_dimension_sprite,
                 copy_dimension_text,
                 copy_dimension_atlas,
+                copy_alpha_mask,
             ).in_set(AouiLoadInputSet))
-            .add_systems(PostUpdate,
-                compute_aoui_transforms::<PrimaryWindow, TRoot, TAll>
-            .in_set(AouiTreeUpdateSet))
+            .add_systems(PostUpdate, (
+                compute_aoui_transforms::<PrimaryWindow, TRoot, TAll>,
+                sequential_depth,
+            ).chain().in_set(AouiTreeUpdateSet))
             .add_systems(PostUpdate, (
                 sync_dimension_atlas,
                 sync_dimension_sprite,
@@ -0,0 +1,9 @@
+#define_import_path bevy_aoui::clip
+
+// Returns `1.0` if `position` is inside the clip rect `(min_x, min_y, max_x, max_y)`, `0.0` otherwise.
+fn aoui_clip(position: vec2<f32>, clip: vec4<f32>) -> f32 {
+    if any(position < clip.xy) || any(position > clip.zw) {
+        return 0.0;
+    }
+    return 1.0;
+}
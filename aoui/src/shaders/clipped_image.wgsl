@@ -0,0 +1,22 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import bevy_aoui::clip::aoui_clip
+
+@group(1) @binding(0)
+var<uniform> color: vec4<f32>;
+
+@group(1) @binding(1)
+var<uniform> clip: vec4<f32>;
+
+@group(1) @binding(2) 
+var texture: texture_2d<f32>;
+
+@group(1) @binding(3) 
+var samplr: sampler;
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    // mesh uvs have y pointing up, images are stored top to bottom.
+    let uv = vec2<f32>(in.uv.x, 1.0 - in.uv.y);
+    let color = color * textureSample(texture, samplr, uv);
+    return color * aoui_clip(in.world_position.xy, clip);
+}
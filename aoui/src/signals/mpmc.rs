@@ -200,6 +200,15 @@ impl<T: AsObject> SignalSender<T> {
     pub fn clone_split<S: CloneSplit<SignalBuilder<T>>>(&self) -> S {
         S::clone_split(SignalBuilder::new(self.signal.clone()))
     }
+
+    /// Erase the input type of this sender, the mapper is preserved.
+    pub fn type_erase(self) -> SignalSender<Object> {
+        SignalSender {
+            signal: self.signal,
+            map: self.map,
+            p: PhantomData
+        }
+    }
 }
 
 impl SignalSender<Object> {
@@ -1,11 +1,14 @@
 use std::mem;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 
 use bevy::{render::texture::Image, math::{Vec2, Rect}, sprite::{TextureAtlas, TextureAtlasBuilder}, log::warn};
 use bevy::asset::{Handle, Assets, AssetServer};
 use bevy::ecs::{component::Component, system::{Query, Commands, Res, ResMut}, entity::Entity};
 
 
-#[derive(Debug, Component)]
+#[derive(Debug, Component, Reflect)]
+#[reflect(Component, Default)]
 pub enum DeferredAtlasBuilder {
     Subdivide {
         image: Handle<Image>,
@@ -19,6 +22,12 @@ pub enum DeferredAtlasBuilder {
     }
 }
 
+impl Default for DeferredAtlasBuilder {
+    fn default() -> Self {
+        Self::Images(Vec::new())
+    }
+}
+
 pub fn build_deferred_atlas(
     mut commands: Commands,
     mut atlas: Query<(Entity, &mut DeferredAtlasBuilder)>,
@@ -2,6 +2,8 @@ use std::{sync::{Mutex, Arc}, ops::Deref, mem};
 
 use bevy::{hierarchy::Children, ecs::{entity::Entity, query::Has}};
 use bevy::window::{Window, PrimaryWindow, CursorIcon};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 use bevy::ecs::{system::{Query, Resource, Res, Commands}, component::Component, query::With};
 use crate::{dsl::{prelude::SignalSender, CloneSplit}, signals::{KeyStorage, AsObject, Signal}, anim::VisibilityToggle};
 use crate::signals::{Object, SignalBuilder};
@@ -17,7 +19,8 @@ use crate::events::{EventFlags, CursorFocus, CursorAction};
 /// on the `App` if your cursor does not revert. 
 /// On the other hand, try remove the [`CursorDefault`] resource
 /// if you want to have more control over cursor logic.
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct SetCursor {
     pub flags: EventFlags,
     pub icon: CursorIcon,
@@ -31,7 +34,7 @@ pub struct SetCursor {
 /// * `CheckButtonState`: For `CheckButton` and `RadioButton`'s status
 /// 
 /// This component uses `Interpolate<Opacity>` if exists, if not, uses `Visibility`.
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
 pub struct DisplayIf<T>(pub T);
 
 pub fn event_conditional_visibility(mut query: Query<(&DisplayIf<EventFlags>, Option<&CursorFocus>, VisibilityToggle)>){
@@ -57,11 +60,13 @@ pub fn check_conditional_visibility(
     })
 }
 /// Marker for sending the `Submit` signal on click.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct Button;
 
 /// This component stores the state of `CheckButton`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Component, Reflect)]
+#[reflect(Component, Default)]
 pub enum CheckButton{
     #[default]
     Unchecked,
@@ -70,8 +75,10 @@ pub enum CheckButton{
 
 /// State of a CheckButton or a RadioButton, 
 /// this propagates to children and can be used in [`DisplayIf`]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub enum CheckButtonState{
+    #[default]
     Unchecked,
     Checked,
 }
@@ -169,7 +176,8 @@ impl PartialEq<Payload> for RadioButton {
 }
 
 /// Component for making `RadioButton` behave like `CheckButton`.
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct RadioButtonCancel;
 
 pub fn button_on_click(
@@ -280,7 +288,8 @@ pub fn generate_check_button_state(
 
 /// Marker component for passing `CursorFocus`, 
 /// `CursorAction` and `CheckButtonState` to their descendants.
-#[derive(Debug, Clone, Copy, Component, Default)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct PropagateFocus;
 
 /// Propagate [`CursorFocus`] and [`CursorAction`] down descendants.
@@ -1,17 +1,32 @@
+use std::marker::PhantomData;
+
 use bevy::{render::{primitives::Frustum, texture::Image, color::Color}, transform::components::GlobalTransform};
+use bevy::app::{App, Plugin, PostUpdate};
 use bevy::core_pipeline::core_2d::Camera2dBundle;
-use bevy::asset::{AssetServer, Handle};
-use bevy::ecs::{component::Component, bundle::Bundle, system::Query, query::With};
+use bevy::asset::{Asset, AssetId, AssetServer, Assets, Handle, load_internal_asset};
+use bevy::ecs::{component::Component, bundle::Bundle, system::{Query, Res, ResMut}, query::{With, Or}};
+use bevy::ecs::{schedule::IntoSystemConfigs, reflect::ReflectComponent};
+use bevy::math::{Rect, UVec2, Vec2, Vec4};
+use bevy::time::Time;
+use bevy::reflect::{Reflect, TypePath, std_traits::ReflectDefault};
+use bevy::sprite::{Material2d, Sprite, TextureAtlasSprite, ExtractedSprite, ExtractedSprites, SpriteSystem};
+use bevy::text::{Text, extract_text2d_sprite};
+use bevy::render::{Extract, ExtractSchedule, RenderApp};
+use bevy::utils::HashMap;
+use bevy::window::{Window, PrimaryWindow};
+use bevy::render::render_resource::{AsBindGroup, Shader, ShaderRef};
 use bevy::render::render_resource::{Extent3d, TextureUsages, TextureDescriptor, TextureDimension, TextureFormat};
-use bevy::render::view::{VisibleEntities, RenderLayers};
+use bevy::render::view::{VisibleEntities, RenderLayers, ViewVisibility, VisibilitySystems};
 use bevy::render::camera::{Camera, CameraRenderGraph, OrthographicProjection, RenderTarget, ScalingMode};
 use bevy::core_pipeline::{core_2d::Camera2d, tonemapping::{Tonemapping, DebandDither}, clear_color::ClearColorConfig};
-use crate::{BuildTransform, Anchor, DimensionData, dsl::CloneSplit};
+use crate::{BuildTransform, Anchor, DimensionData, Clipping, RotatedRect, AouiMaterial, AouiMaterialPlugin, dsl::CloneSplit};
+use crate::schedule::AouiStoreOutputSet;
 
 use crate::dsl::DslInto;
 
 /// Marker component that indicates the camera is used for clipping its contents.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct CameraClip;
 
 /// A bundle that spawns a camera that draws its enclosed rectangle to a render target.
@@ -30,6 +45,47 @@ pub struct ScopedCameraBundle {
     pub render_layer: RenderLayers,
     pub build: BuildTransform,
     pub global: GlobalTransform,
+    pub resize: RenderTargetResize,
+}
+
+/// Resizes the render target of a [`ScopedCameraBundle`] to match its physical pixel size,
+/// accounting for the primary window's scale factor.
+///
+/// Disabled by default.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct RenderTargetResize {
+    /// If set, resize the render target to the frame's physical pixel size times this value.
+    pub scale: Option<f32>,
+    /// Time in seconds the frame's size must remain unchanged before reallocating.
+    pub debounce: f32,
+    pending: Option<(UVec2, f32)>,
+}
+
+impl Default for RenderTargetResize {
+    fn default() -> Self {
+        Self {
+            scale: None,
+            debounce: 0.2,
+            pending: None,
+        }
+    }
+}
+
+impl RenderTargetResize {
+    /// Resize the render target to the frame's physical pixel size times `scale`.
+    pub fn new(scale: f32) -> Self {
+        Self {
+            scale: Some(scale),
+            ..Default::default()
+        }
+    }
+
+    /// Set the debounce time in seconds.
+    pub fn with_debounce(mut self, seconds: f32) -> Self {
+        self.debounce = seconds;
+        self
+    }
 }
 
 
@@ -85,17 +141,306 @@ impl ScopedCameraBundle {
             render_layer: layer.dinto(),
             build: BuildTransform(Anchor::Center),
             global: GlobalTransform::default(),
+            resize: RenderTargetResize::default(),
         }
     }
+
+    /// Resize the render target to the frame's physical pixel size times `scale`.
+    pub fn with_auto_resize(mut self, scale: f32) -> Self {
+        self.resize = RenderTargetResize::new(scale);
+        self
+    }
 }
 
 pub fn sync_camera_dimension(
-    mut query: Query<(&DimensionData, &mut OrthographicProjection), With<CameraClip>>,
+    time: Res<Time>,
+    mut images: ResMut<Assets<Image>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<(&DimensionData, &Camera, &mut OrthographicProjection, Option<&mut RenderTargetResize>), With<CameraClip>>,
 ) {
-    for (dimension, mut proj) in query.iter_mut() {
+    let scale_factor = windows.get_single().map(|x| x.scale_factor() as f32).unwrap_or(1.0);
+    for (dimension, camera, mut proj, resize) in query.iter_mut() {
         proj.scaling_mode = ScalingMode::Fixed { 
             width: dimension.size.x, 
             height: dimension.size.y 
         };
+        let Some(mut resize) = resize else { continue };
+        let Some(scale) = resize.scale else { continue };
+        let RenderTarget::Image(handle) = &camera.target else { continue };
+        let Some(current) = images.get(handle).map(|x| x.size()) else { continue };
+        let size = (dimension.size * scale * scale_factor).round().max(Vec2::ONE).as_uvec2();
+        if size == current {
+            if resize.pending.is_some() {
+                resize.pending = None;
+            }
+            continue;
+        }
+        let elapsed = match resize.pending {
+            Some((pending, elapsed)) if pending == size => elapsed + time.delta_seconds(),
+            _ => 0.0,
+        };
+        if elapsed < resize.debounce {
+            resize.pending = Some((size, elapsed));
+            continue;
+        }
+        resize.pending = None;
+        if let Some(image) = images.get_mut(handle) {
+            image.resize(Extent3d {
+                width: size.x,
+                height: size.y,
+                ..Default::default()
+            });
+        }
+    }
+}
+
+pub const CLIP_SHADER: Handle<Shader> =           Handle::weak_from_u128(270839355282343875567970925758141260080);
+pub const CLIPPED_IMAGE_SHADER: Handle<Shader> =  Handle::weak_from_u128(270839355282343875567970925758141260081);
+
+/// Clip rect uniform of a region that clips nothing.
+pub const UNCLIPPED: Vec4 = Vec4::new(f32::MIN, f32::MIN, f32::MAX, f32::MAX);
+
+/// A [`Material2d`] with a clip rect uniform, fed from [`Clipping`] by [`ClippingMaterialPlugin`].
+///
+/// The clip rect is `(min_x, min_y, max_x, max_y)` in world space.
+/// This is a lightweight alternative to [`ScopedCameraBundle`],
+/// but rotated or skewed clipping regions are approximated by their bounding boxes.
+///
+/// In the fragment shader, import `bevy_aoui::clip::aoui_clip`, and multiply
+/// the output by `aoui_clip(in.world_position.xy, clip)`.
+///
+/// Widgets sharing a material with different clip rects receive their own copy of the material.
+///
+/// Bevy's `Sprite`, `TextureAtlasSprite` and `Text` are clipped by [`crop_clipped_sprites`] instead.
+pub trait ClippingMaterial: Material2d {
+    /// Obtain the current clip rect.
+    fn clip_rect(&self) -> Vec4;
+    /// Set the clip rect.
+    fn set_clip_rect(&mut self, rect: Vec4);
+}
+
+/// Clip rect uniform of a [`Clipping`] component.
+pub fn clip_rect_uniform(clipping: &Clipping) -> Vec4 {
+    match clipping.global.bounding_rect() {
+        Some(rect) => Vec4::new(rect.min.x, rect.min.y, rect.max.x, rect.max.y),
+        None => UNCLIPPED,
+    }
+}
+
+/// Write clip rects of [`ClippingMaterial`]s.
+///
+/// A material shared by widgets with different clip rects is cloned,
+/// so each clip rect has its own material.
+pub fn sync_clip_material<M: ClippingMaterial>(
+    mut query: Query<(&mut Handle<M>, &Clipping)>,
+    mut assets: ResMut<Assets<M>>
+) {
+    // The clip rect each material is used with, claimed by its first widget.
+    let mut claimed = HashMap::<AssetId<M>, Vec4>::new();
+    for (mut handle, clipping) in query.iter_mut() {
+        let rect = clip_rect_uniform(clipping);
+        let claim = *claimed.entry(handle.id()).or_insert(rect);
+        if claim != rect {
+            let Some(mut material) = assets.get(handle.as_ref()).cloned() else { continue };
+            material.set_clip_rect(rect);
+            *handle = assets.add(material);
+            claimed.insert(handle.id(), rect);
+            continue;
+        }
+        // Avoid marking the asset as changed.
+        if assets.get(handle.as_ref()).map(|x| x.clip_rect() != rect) != Some(true) { continue; }
+        let Some(asset) = assets.get_mut(handle.as_ref()) else { continue };
+        asset.set_clip_rect(rect);
+    }
+}
+
+/// Hide sprites and texts entirely outside of their clipping region.
+///
+/// Partially visible sprites and texts are cropped by [`crop_clipped_sprites`].
+pub fn cull_clipped(
+    mut query: Query<(&RotatedRect, &Clipping, &mut ViewVisibility), Or<(With<Sprite>, With<TextureAtlasSprite>, With<Text>)>>,
+) {
+    for (rect, clipping, mut vis) in query.iter_mut() {
+        if !vis.get() { continue; }
+        let Some(region) = clipping.global.bounding_rect() else { continue };
+        let bounds = rect.corners().into_iter()
+            .fold(Rect::new(f32::MAX, f32::MAX, f32::MIN, f32::MIN), |rect, v| Rect {
+                min: rect.min.min(v),
+                max: rect.max.max(v),
+            });
+        if region.intersect(bounds).is_empty() {
+            *vis = ViewVisibility::HIDDEN;
+        }
+    }
+}
+
+/// Crop a sprite quad to a world space rect, returns false if nothing is visible.
+///
+/// Sprites with rotation or skew are not cropped.
+fn crop_sprite(sprite: &mut ExtractedSprite, region: Rect, image_size: Option<Vec2>) -> bool {
+    let affine = sprite.transform.affine();
+    let (x_axis, y_axis) = (affine.matrix3.x_axis, affine.matrix3.y_axis);
+    if x_axis.y.abs() > f32::EPSILON || y_axis.x.abs() > f32::EPSILON {
+        return true;
+    }
+    let scale = Vec2::new(x_axis.x, y_axis.y);
+    if scale.x == 0.0 || scale.y == 0.0 {
+        return true;
+    }
+    let Some(texture) = sprite.rect.or(image_size.map(|size| Rect::from_corners(Vec2::ZERO, size))) else {
+        return true;
+    };
+    let size = sprite.custom_size.unwrap_or(texture.size());
+    if size.x <= 0.0 || size.y <= 0.0 {
+        return true;
+    }
+    // Compare in the sprite's local space, where the quad is axis aligned.
+    let min = (-sprite.anchor - 0.5) * size;
+    let quad = Rect { min, max: min + size };
+    let translation = affine.translation.truncate();
+    let visible = quad.intersect(Rect::from_corners(
+        (region.min - translation) / scale,
+        (region.max - translation) / scale,
+    ));
+    if visible.is_empty() {
+        return false;
+    }
+    if visible == quad {
+        return true;
+    }
+    let min = (visible.min - quad.min) / size;
+    let max = (visible.max - quad.min) / size;
+    // Local y points up, texture y points down.
+    let (u0, u1) = if sprite.flip_x { (1.0 - max.x, 1.0 - min.x) } else { (min.x, max.x) };
+    let (v0, v1) = if sprite.flip_y { (min.y, max.y) } else { (1.0 - max.y, 1.0 - min.y) };
+    sprite.rect = Some(Rect {
+        min: texture.min + Vec2::new(u0, v0) * texture.size(),
+        max: texture.min + Vec2::new(u1, v1) * texture.size(),
+    });
+    sprite.custom_size = Some(visible.size());
+    sprite.anchor = -visible.min / visible.size() - 0.5;
+    true
+}
+
+/// Crop extracted sprites and text glyphs to the bounding rect of their clipping region,
+/// so partially visible sprites and texts are clipped per pixel.
+///
+/// Rotated or skewed sprites and texts are not cropped, and only culled by [`cull_clipped`].
+pub fn crop_clipped_sprites(
+    mut extracted: ResMut<ExtractedSprites>,
+    clipping: Extract<Query<&Clipping>>,
+    images: Extract<Res<Assets<Image>>>,
+) {
+    extracted.sprites.retain(|entity, sprite| {
+        let Ok(clipping) = clipping.get(sprite.original_entity.unwrap_or(*entity)) else { return true };
+        let Some(region) = clipping.global.bounding_rect() else { return true };
+        let image_size = match sprite.rect {
+            Some(_) => None,
+            None => images.get(sprite.image_handle_id).map(|image| image.size_f32()),
+        };
+        crop_sprite(sprite, region, image_size)
+    });
+}
+
+/// Adds clip rect synchronization for a [`ClippingMaterial`].
+///
+/// This does not add the corresponding [`AouiMaterialPlugin`] or [`Material2dPlugin`](bevy::sprite::Material2dPlugin).
+#[derive(Debug)]
+pub struct ClippingMaterialPlugin<M: ClippingMaterial>(PhantomData<M>);
+
+impl<M: ClippingMaterial> Default for ClippingMaterialPlugin<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<M: ClippingMaterial> Plugin for ClippingMaterialPlugin<M> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, sync_clip_material::<M>.in_set(AouiStoreOutputSet));
+    }
+}
+
+/// A clipped image, use with `material_sprite!` as a lightweight
+/// replacement of a `Sprite` inside a clipping region.
+#[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
+pub struct ClippedImageMaterial {
+    /// Color multiplied to the image.
+    #[uniform(0)]
+    pub color: Color,
+    /// Clip rect, written by [`sync_clip_material`].
+    #[uniform(1)]
+    pub clip: Vec4,
+    /// The image, if `None`, draws a solid color.
+    #[texture(2)]
+    #[sampler(3)]
+    pub image: Option<Handle<Image>>,
+}
+
+impl Default for ClippedImageMaterial {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            clip: UNCLIPPED,
+            image: None,
+        }
+    }
+}
+
+impl ClippedImageMaterial {
+    pub fn new(image: Handle<Image>) -> Self {
+        Self {
+            image: Some(image),
+            ..Default::default()
+        }
+    }
+
+    pub fn color(color: Color) -> Self {
+        Self {
+            color,
+            ..Default::default()
+        }
+    }
+}
+
+impl Material2d for ClippedImageMaterial {
+    fn fragment_shader() -> ShaderRef {
+        ShaderRef::Handle(CLIPPED_IMAGE_SHADER)
+    }
+}
+
+impl AouiMaterial for ClippedImageMaterial {
+    fn set_opacity(&mut self, opacity: f32) {
+        self.color.set_a(opacity);
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.color = color
+    }
+}
+
+impl ClippingMaterial for ClippedImageMaterial {
+    fn clip_rect(&self) -> Vec4 {
+        self.clip
+    }
+
+    fn set_clip_rect(&mut self, rect: Vec4) {
+        self.clip = rect
+    }
+}
+
+/// Registers shaders and systems for render clipping.
+pub(crate) fn register_render_clipping(app: &mut App) {
+    // Skipped in headless apps without a renderer.
+    if app.world.contains_resource::<Assets<Shader>>() {
+        load_internal_asset!(app, CLIP_SHADER, "../shaders/clip.wgsl", Shader::from_wgsl);
+        load_internal_asset!(app, CLIPPED_IMAGE_SHADER, "../shaders/clipped_image.wgsl", Shader::from_wgsl);
+    }
+    app.add_plugins(AouiMaterialPlugin::<ClippedImageMaterial>::default());
+    app.add_plugins(ClippingMaterialPlugin::<ClippedImageMaterial>::default());
+    app.add_systems(PostUpdate, cull_clipped.after(VisibilitySystems::CheckVisibility));
+    if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+        render_app.add_systems(ExtractSchedule, crop_clipped_sprites
+            .after(SpriteSystem::ExtractSprites)
+            .after(extract_text2d_sprite));
     }
 }
@@ -1,4 +1,6 @@
 use std::sync::Arc;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::std_traits::ReflectDefault;
 use atomic::{Atomic, Ordering};
 use bevy::ecs::entity::Entity;
 use bevy::ecs::query::Has;
@@ -7,13 +9,14 @@ use bevy::window::{Window, PrimaryWindow};
 use bevy::math::{Vec2, IVec2};
 use bevy::hierarchy::{Children, Parent};
 use bevy::ecs::{component::Component, system::{Commands, Res, Query}};
+use bevy::time::Time;
 use crate::DimensionData;
 use crate::dsl::CloneSplit;
 use crate::{signals::KeyStorage, AouiREM, Transform2D, Anchor, anim::Attr, layout::Container};
 use crate::anim::Offset;
-use crate::events::{Handlers, EvMouseWheel, MouseWheelAction, EvPositionFactor};
+use crate::events::{Handlers, EvMouseWheel, MouseWheelAction, EvPositionFactor, CursorState};
 
-use super::{scroll::{Scrolling, ScrollDiscrete}, drag::Dragging};
+use super::{scroll::{Scrolling, ScrollDiscrete, ScrollPhysics, ScrollSnap}, drag::Dragging};
 
 fn filter_nan(v: Vec2) -> Vec2 {
     Vec2::new(
@@ -30,7 +33,8 @@ fn flip_vec(v: Vec2, [x, y]: &[bool; 2]) -> Vec2 {
 }
 
 /// A marker component for denoting position changed via dragging or scrolling this frame.
-#[derive(Debug, Clone, Component, Reflect)]
+#[derive(Debug, Clone, Component, Reflect, Default)]
+#[reflect(Component, Default)]
 #[component(storage="SparseSet")]
 pub struct PositionChanged;
 
@@ -44,6 +48,7 @@ pub fn remove_position_changed(mut commands: Commands,
 }
 /// A shared percentage based position.
 #[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct SharedPosition{
     #[reflect(ignore)]
     pub position: Arc<Atomic<Vec2>>,
@@ -84,26 +89,32 @@ impl SharedPosition {
 
 /// Constraints this based on its parent.
 #[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct DragConstraint;
 
 /// Constraints children based on this entity.
 #[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct ScrollConstraint;
 
 
 pub fn scroll_constraint(
     mut commands: Commands,
     storage: Res<KeyStorage>,
+    time: Res<Time>,
+    state: Res<CursorState>,
     rem: Option<Res<AouiREM>>,
-    query: Query<(Entity, &Scrolling, &DimensionData, Option<&SharedPosition>, &Children, 
+    mut query: Query<(Entity, &Scrolling, &DimensionData, Option<&SharedPosition>, &Children, 
         Option<&Handlers<EvMouseWheel>>,
         Option<&Handlers<EvPositionFactor>>,
+        Option<&mut ScrollPhysics>,
+        Option<&mut ScrollSnap>,
         Has<PositionChanged>,
     ), With<ScrollConstraint>>,
     mut child_query: Query<(&DimensionData, Attr<Transform2D, Offset>, Option<&Children>)>,
 ) {
     let rem = rem.map(|x|x.get()).unwrap_or(16.0);
-    for (entity, scroll, dimension, shared, children, scroll_handler, fac_handler, changed) in query.iter() {
+    for (entity, scroll, dimension, shared, children, scroll_handler, fac_handler, physics, snap, changed) in query.iter_mut() {
         let size = dimension.size;
         let mut commands = commands.entity(entity);
         if children.len() != 1 {
@@ -143,11 +154,23 @@ pub fn scroll_constraint(
                 (size_max - max).max(size_min - min).max(Vec2::ZERO).min(constraint_max),
             );
             let Ok(mut transform) = child_query.get_mut(container).map(|(_, t, _)| t) else {continue};
-            let offset = offset.clamp(min, max);
+            if let Some(mut snap) = snap {
+                snap.bounds = Some((min, max));
+            }
+            // `ScrollPhysics` allows overscroll and springs back on its own.
+            let mut physics = physics;
+            let overscroll = match physics.as_mut() {
+                Some(physics) => {
+                    physics.bounds = Some((min, max));
+                    Vec2::splat(physics.overscroll.max(0.0))
+                },
+                None => Vec2::ZERO,
+            };
+            let offset = offset.clamp(min - overscroll, max + overscroll);
             transform.force_set(offset);
             match shared {
                 None if changed => {
-                    let fac = filter_nan((offset - min) / (max - min));
+                    let fac = filter_nan((offset.clamp(min, max) - min) / (max - min));
                     match (scroll.x_scroll(), scroll.y_scroll()) {
                         (true, false) => {
                             let value = fac.x.clamp(0.0, 1.0);
@@ -180,7 +203,7 @@ pub fn scroll_constraint(
                             piping.handle(&mut commands, &storage, action);
                         }
                     }
-                    let fac = filter_nan((offset - min) / (max - min));
+                    let fac = filter_nan((offset.clamp(min, max) - min) / (max - min));
                     position.store(flip_vec(fac, flip), Ordering::Relaxed);
     
                     match (scroll.x_scroll(), scroll.y_scroll()) {
@@ -205,7 +228,11 @@ pub fn scroll_constraint(
                 Some(SharedPosition{ position, flip }) => {
                     let fac = flip_vec(position.load(Ordering::Relaxed), flip);
                     if fac.is_nan() { continue; }
-                    transform.force_set((max - min) * fac + min);
+                    let target = (max - min) * fac + min;
+                    if let Some(physics) = physics.as_mut() {
+                        physics.follow(target - offset, state.dragging(), time.delta_seconds());
+                    }
+                    transform.force_set(target);
                 },
             }
         }            
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res, Commands, Local};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+
+use crate::signals::{KeyStorage, AsObject, Object};
+use crate::events::{Handlers, EvDragEnter, EvDragLeave, EvDropReceived};
+use crate::events::{EventFlags, CursorFocus, CursorAction, CursorState, ActiveDetection, CursorDetection};
+
+/// Data carried by a dragged widget, received by drop targets
+/// with [`EvDropReceived`].
+///
+/// Requires a `*Drag` listener on the widget.
+#[derive(Debug, Clone, Component)]
+pub struct DragPayload(pub Object);
+
+impl DragPayload {
+    pub fn new(value: impl AsObject) -> Self {
+        Self(Object::new(value))
+    }
+
+    pub fn get<T: AsObject>(&self) -> Option<T> {
+        self.0.get()
+    }
+}
+
+/// Filters [`DragPayload`]s a drop target accepts.
+///
+/// Drop targets require the `Drop` listener,
+/// targets without `AcceptDrop` accept all payloads.
+/// A refused drop is not passed on to targets below,
+/// and does not send `Drop`, which is refused if the dragged widget has no payload.
+#[derive(Clone, Component)]
+pub struct AcceptDrop(Arc<dyn Fn(&Object) -> bool + Send + Sync>);
+
+impl std::fmt::Debug for AcceptDrop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AcceptDrop").finish()
+    }
+}
+
+impl AcceptDrop {
+    /// Accept payloads that satisfy a predicate.
+    pub fn new(f: impl Fn(&Object) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Accept payloads of type `T`.
+    pub fn of<T: AsObject>() -> Self {
+        Self::new(|obj| obj.get_ref::<T>().is_some())
+    }
+
+    pub fn accepts(&self, payload: &Object) -> bool {
+        (self.0)(payload)
+    }
+}
+
+/// Marks a drop target being hovered by an accepted [`DragPayload`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+#[component(storage="SparseSet")]
+pub struct DragHover {
+    /// The dragged widget.
+    pub source: Entity,
+}
+
+impl Default for DragHover {
+    fn default() -> Self {
+        Self { source: Entity::PLACEHOLDER }
+    }
+}
+
+/// Data sent by [`EvDropReceived`].
+#[derive(Debug, Clone)]
+pub struct DropReceived {
+    /// The dragged widget.
+    pub source: Entity,
+    /// The [`DragPayload`] of the dragged widget.
+    pub payload: Object,
+}
+
+impl PartialEq for DropReceived {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source && self.payload.equal_to(&other.payload)
+    }
+}
+
+const DRAGGING: EventFlags = EventFlags::LeftDrag
+    .const_or(EventFlags::MidDrag)
+    .const_or(EventFlags::RightDrag)
+    .const_or(EventFlags::OtherDrag);
+
+const DRAG_END: EventFlags = EventFlags::DragEnd
+    .const_or(EventFlags::DoubleClick);
+
+/// Send [`EvDragEnter`], [`EvDragLeave`] and [`EvDropReceived`] to drop targets
+/// of a widget with a [`DragPayload`].
+pub fn drag_and_drop(
+    mut commands: Commands,
+    storage: Res<KeyStorage>,
+    state: Res<CursorState>,
+    sources: Query<(Entity, &DragPayload, Option<&CursorFocus>, Option<&CursorAction>)>,
+    targets: Query<(Entity, &EventFlags, Option<&AcceptDrop>, ActiveDetection, CursorDetection)>,
+    enter: Query<&Handlers<EvDragEnter>>,
+    leave: Query<&Handlers<EvDragLeave>>,
+    receive: Query<&Handlers<EvDropReceived>>,
+    mut hovered: Local<Option<Entity>>,
+) {
+    let mut dragged = None;
+    let mut dropped = None;
+    for (entity, payload, focus, action) in sources.iter() {
+        if focus.map(|x| x.intersects(DRAGGING)).unwrap_or(false) {
+            dragged = Some((entity, payload));
+        } else if action.map(|x| x.intersects(DRAG_END)).unwrap_or(false) {
+            dropped = Some((entity, payload));
+        }
+    }
+    let position = state.cursor_position();
+    // A refusing target blocks accepting targets below it.
+    let target = dragged.or(dropped).and_then(|(source, payload)| targets.iter()
+        .filter(|(entity, flags, _, active, hitbox)| *entity != source
+            && flags.contains(EventFlags::Drop)
+            && active.is_active()
+            && hitbox.contains(position))
+        .max_by(|(.., a), (.., b)| a.compare(b))
+        .filter(|(_, _, accept, ..)| accept.map(|x| x.accepts(&payload.0)).unwrap_or(true))
+        .map(|(entity, ..)| entity));
+
+    let target_hovered = if dragged.is_some() { target } else { None };
+    if *hovered != target_hovered {
+        if let Some(entity) = hovered.take() {
+            if let Some(mut commands) = commands.get_entity(entity) {
+                commands.remove::<DragHover>();
+                if let Ok(handlers) = leave.get(entity) {
+                    handlers.handle(&mut commands, &storage, ());
+                }
+            }
+        }
+        if let (Some(entity), Some((source, _))) = (target_hovered, dragged) {
+            let mut commands = commands.entity(entity);
+            commands.insert(DragHover { source });
+            if let Ok(handlers) = enter.get(entity) {
+                handlers.handle(&mut commands, &storage, ());
+            }
+        }
+        *hovered = target_hovered;
+    }
+
+    if let (Some(entity), Some((source, payload))) = (target, dropped) {
+        if let Ok(handlers) = receive.get(entity) {
+            handlers.handle(&mut commands.entity(entity), &storage, DropReceived {
+                source,
+                payload: payload.0.clone(),
+            });
+        }
+    }
+}
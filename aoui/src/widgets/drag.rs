@@ -1,4 +1,6 @@
 use bevy::math::Vec2;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 use bevy::ecs::{component::Component, query::Without, bundle::Bundle, entity::Entity};
 use bevy::ecs::system::{Query, Res, Commands};
 use crate::dsl::DslInto;
@@ -32,7 +34,8 @@ pub use super::constraints::DragConstraint;
 ///     For example synchronizing scrollbar with a textbox.
 /// * [`Handlers<EvPositionFac>`]: A signal that sends a value in `0..=1` in its constraints when being dragged.
 
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct Dragging {
     pub x: bool,
     pub y: bool,
@@ -70,7 +73,8 @@ impl Default for Dragging {
 }
 
 /// Component that moves the sprite back to its original position if dropped. 
-#[derive(Debug, Clone, Copy, Component, Default)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct DragSnapBack {
     drag_start: Option<Vec2>,
 }
@@ -84,23 +88,27 @@ impl DragSnapBack {
 }
 
 
+/// Sends `DragState::Start` and records the drag start on the frame
+/// `*Drag` is first sent, i.e. when the [`DragThreshold`](crate::events::DragThreshold) is crossed.
 pub fn drag_start(
     mut commands: Commands,
     storage: Res<KeyStorage>,
-    send: Query<(Entity, &CursorAction, &Handlers<EvMouseDrag>), Without<Dragging>>,
-    mut receive: Query<(&Invoke<Dragging>, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>), Without<CursorAction>>,
-    mut query: Query<(&CursorAction, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>)>,
+    state: Res<CursorState>,
+    send: Query<(Entity, &CursorFocus, &Handlers<EvMouseDrag>), Without<Dragging>>,
+    mut receive: Query<(&Invoke<Dragging>, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>), Without<CursorFocus>>,
+    mut query: Query<(&CursorFocus, &mut Dragging, Attr<Transform2D, Offset>, Option<&mut DragSnapBack>)>,
 ) {
+    if !state.drag_just_started() { return; }
     for (entity, focus, send) in send.iter() {
         let mut commands = commands.entity(entity);
-        if focus.intersects(EventFlags::LeftDown | EventFlags::MidDown | EventFlags:: RightDown)  {
+        if focus.intersects(EventFlags::LeftDrag | EventFlags::MidDrag | EventFlags:: RightDrag | EventFlags::OtherDrag)  {
             send.handle(&mut commands, &storage, DragState::Start);
         }
     }
 
     let iter = query.iter_mut()
-        .filter_map(|(action, drag, transform, snap)| {
-            if action.intersects(EventFlags::LeftDown | EventFlags::MidDown | EventFlags:: RightDown) {
+        .filter_map(|(focus, drag, transform, snap)| {
+            if focus.intersects(EventFlags::LeftDrag | EventFlags::MidDrag | EventFlags:: RightDrag | EventFlags::OtherDrag) {
                 Some((drag, transform, snap))
             } else {
                 None
@@ -153,7 +161,7 @@ pub fn dragging(
 
     for (entity, focus, send) in send.iter() {
         let mut commands = commands.entity(entity);
-        if !focus.intersects(EventFlags::LeftDrag | EventFlags::MidDrag | EventFlags:: RightDrag)  {
+        if !focus.intersects(EventFlags::LeftDrag | EventFlags::MidDrag | EventFlags:: RightDrag | EventFlags::OtherDrag)  {
             continue;
         }
         send.handle(&mut commands, &storage, DragState::Dragging);
@@ -161,7 +169,7 @@ pub fn dragging(
 
     let iter = query.iter_mut()
         .filter_map(|(entity, focus, drag, transform)| {
-            focus.intersects(EventFlags::LeftDrag | EventFlags::MidDrag | EventFlags:: RightDrag)
+            focus.intersects(EventFlags::LeftDrag | EventFlags::MidDrag | EventFlags:: RightDrag | EventFlags::OtherDrag)
                 .then_some((entity, drag, transform))
         }).chain(receive.iter_mut()
         .filter_map(|(entity, drag, transform, recv)|
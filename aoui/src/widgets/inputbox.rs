@@ -1,7 +1,9 @@
 use bevy::hierarchy::Children;
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 use bevy::asset::{Handle, Assets};
 use bevy::input::{keyboard::KeyCode, Input};
-use bevy::ecs::{query::Changed, event::EventReader, system::Commands};
+use bevy::ecs::{query::Changed, event::EventReader, system::Commands, removal_detection::RemovedComponents, change_detection::DetectChanges, world::Ref};
 use bevy::render::color::Color;
 use bevy::window::{Window, PrimaryWindow, ReceivedCharacter};
 use bevy::text::{Text, Font};
@@ -10,17 +12,18 @@ use crate::{DimensionData, Dimension};
 use crate::dimension::DimensionMut;
 use crate::signals::{KeyStorage, Invoke, ReceiveInvoke};
 use crate::{RotatedRect, Transform2D, bundles::AouiTextBundle};
-use crate::events::{CursorState, CursorFocus, CursorClickOutside, EventFlags, CursorAction, ActiveDetection, EvTextChange, EvTextSubmit, Handlers};
+use crate::events::{CursorState, CursorFocus, CursorClickOutside, EventFlags, CursorAction, ActiveDetection, EvTextChange, EvTextSubmit, Handlers, KeyboardFocus};
 use ab_glyph::Font as FontTrait;
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, Reflect)]
 enum LeftRight {
     Left, #[default] Right,
 }
 
 
 /// Color of an [`InputBox`].
-#[derive(Debug, Clone, Copy, Component)]
+#[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct TextColor(pub Color);
 
 impl TextColor {
@@ -33,7 +36,7 @@ impl TextColor {
 }
 
 /// If we deny overflowing input or not.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
 pub enum InputOverflow {
     /// Deny overflow.
     #[default]
@@ -57,7 +60,8 @@ pub enum InputOverflow {
 /// 
 /// Warning: This widget does not rebuild its glyph entities every frame,
 /// might not behave properly if tempered externally.
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct InputBox {
     overflow: InputOverflow,
     cursor_start: usize,
@@ -73,7 +77,8 @@ impl ReceiveInvoke for InputBox {
 }
 
 /// Marker component for a empty frame containing individual glyphs.
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct InputBoxText;
 
 /// Marker component for a vertical bar of the cursor.
@@ -81,7 +86,8 @@ pub struct InputBoxText;
 /// This component can be any sprite.
 /// 
 /// Requires `Center`, `TopCenter` or `BottomCenter` Anchor to function properly.
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct InputBoxCursorBar;
 
 /// Marker component for the area of the cursor.
@@ -90,7 +96,8 @@ pub struct InputBoxCursorBar;
 /// updates alongside dimension can be used here.
 /// 
 /// Requires `Center`, `TopCenter` or `BottomCenter` Anchor to function properly.
-#[derive(Debug, Component, Default)]
+#[derive(Debug, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct InputBoxCursorArea;
 
 impl InputBox {
@@ -332,6 +339,23 @@ pub fn text_on_mouse_down(
     }
 }
 
+/// Synchronize focus of an [`InputBox`] with [`KeyboardFocus`].
+pub fn inputbox_keyboard_focus(
+    mut removed: RemovedComponents<KeyboardFocus>,
+    mut query: Query<(&mut InputBox, Option<Ref<KeyboardFocus>>)>,
+) {
+    for entity in removed.read() {
+        if let Ok((mut input_box, None)) = query.get_mut(entity) {
+            input_box.set_focus(false);
+        }
+    }
+    for (mut input_box, focus) in query.iter_mut() {
+        if focus.map(|x| x.is_added()).unwrap_or(false) {
+            input_box.set_focus(true);
+        }
+    }
+}
+
 pub fn text_on_mouse_double_click(
     mut query: Query<(&mut InputBox, &CursorAction)>
 ) {
@@ -23,6 +23,8 @@
 //! | [`Scrolling`](scroll::Scrolling) | Enable scrolling of children. |
 //! | [`ScrollConstraint`](scroll::ScrollConstraint) | Constraint scrolling to the sprite's dimension. |
 //! | [`ScrollDiscrete`](scroll::ScrollDiscrete) | Discrete scrolling for [`Layout`](crate::layout::Layout). |
+//! | [`ScrollPhysics`](scroll::ScrollPhysics) | Smooth scrolling, inertia and overscroll. |
+//! | [`ScrollSnap`](scroll::ScrollSnap) | Snap scrolling to children or pages. |
 //! | [`SharedPosition`] | Share position between draggable/scrollable widgets. |
 //! 
 //! # Dragging
@@ -34,11 +36,20 @@
 //! | [`DragSnapBack`](drag::DragSnapBack) | Snap dragged sprite back to the source. |
 //! | [`SharedPosition`] | Share position between draggable/scrollable widgets. |
 //! 
+//! # Drag and Drop
+//! 
+//! | Component | Description |
+//! | --------- | ----------- |
+//! | [`DragPayload`](dnd::DragPayload) | Data carried by a dragged widget. |
+//! | [`AcceptDrop`](dnd::AcceptDrop) | Filters payloads a drop target accepts. |
+//! | [`DragHover`](dnd::DragHover) | Marker for a drop target hovered by an accepted payload. |
+//! 
 //! # Clipping
 //! 
 //! | Bundle | Description |
 //! | --------- | ----------- |
 //! | [`ScopedCameraBundle`](clipping::ScopedCameraBundle) | Bind a camera to a sprite's `RotatedRect`. |
+//! | [`ClippedImageMaterial`](clipping::ClippedImageMaterial) | An image clipped by a clip rect uniform, without a dedicated camera. |
 //! 
 //! # InputBox
 //! 
@@ -56,17 +67,27 @@
 //! | --------- | ----------- |
 //! | [`RichTextBuilder`](richtext::RichTextBuilder) | Builder for `rich_text` |
 //! 
+//! # Tooltip
+//! 
+//! | Component | Description |
+//! | --------- | ----------- |
+//! | [`Tooltip`](tooltip::Tooltip) | Display a sprite after hovering for some time. |
+//! | [`TooltipOf`](tooltip::TooltipOf) | Marker for the sprite displayed by a `Tooltip`. |
+//! 
 pub mod inputbox;
 pub mod drag;
+pub mod dnd;
 pub mod richtext;
 pub mod scroll;
 pub mod clipping;
 pub mod button;
+pub mod tooltip;
 mod constraints;
 mod atlas;
 pub use atlas::DeferredAtlasBuilder;
 pub use constraints::SharedPosition;
 use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::reflect::ReflectComponent;
 use bevy::app::{Plugin, PreUpdate, Update, PostUpdate, Last};
 
 use crate::{schedule::{AouiButtonEventSet, AouiWidgetEventSet, AouiLoadInputSet, AouiStoreOutputSet, AouiCleanupSet, AouiEventSet}, events::{CursorAction, CursorFocus}};
@@ -77,7 +98,43 @@ pub(crate) struct WidgetsPlugin;
 
 impl Plugin for WidgetsPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
+        clipping::register_render_clipping(app);
         app
+            .register_type::<button::Button>()
+            .register_type::<button::CheckButton>()
+            .register_type::<button::CheckButtonState>()
+            .register_type::<button::RadioButtonCancel>()
+            .register_type::<button::PropagateFocus>()
+            .register_type::<button::SetCursor>()
+            .register_type::<button::DisplayIf<crate::events::EventFlags>>()
+            .register_type::<button::DisplayIf<CheckButtonState>>()
+            // Generic components register their type data per type.
+            .register_type_data::<button::DisplayIf<crate::events::EventFlags>, ReflectComponent>()
+            .register_type_data::<button::DisplayIf<CheckButtonState>, ReflectComponent>()
+            .register_type::<inputbox::TextColor>()
+            .register_type::<inputbox::InputOverflow>()
+            .register_type::<inputbox::InputBox>()
+            .register_type::<inputbox::InputBoxText>()
+            .register_type::<inputbox::InputBoxCursorBar>()
+            .register_type::<inputbox::InputBoxCursorArea>()
+            .register_type::<drag::Dragging>()
+            .register_type::<drag::DragSnapBack>()
+            .register_type::<dnd::DragHover>()
+            .register_type::<scroll::Scrolling>()
+            .register_type::<scroll::ScrollDiscrete>()
+            .register_type::<scroll::ScrollPhysics>()
+            .register_type::<scroll::ScrollSnap>()
+            .register_type::<scroll::SnapPoints>()
+            .register_type::<clipping::CameraClip>()
+            .register_type::<clipping::RenderTargetResize>()
+            .register_type::<richtext::GlyphSpace>()
+            .register_type::<constraints::PositionChanged>()
+            .register_type::<constraints::DragConstraint>()
+            .register_type::<constraints::ScrollConstraint>()
+            .register_type::<SharedPosition>()
+            .register_type::<DeferredAtlasBuilder>()
+            .register_type::<tooltip::TooltipOf>()
+            .register_type::<tooltip::TooltipPlacement>()
             .add_systems(PreUpdate, (
                 button::button_on_click,
                 button::check_button_on_click,
@@ -90,6 +147,7 @@ impl Plugin for WidgetsPlugin {
                 inputbox::text_on_mouse_down,
                 inputbox::text_on_click_outside,
                 inputbox::text_on_mouse_double_click,
+                inputbox::inputbox_keyboard_focus.before(inputbox::inputbox_keyboard),
                 inputbox::inputbox_keyboard,
                 button::propagate_focus::<CursorAction>,
                 button::propagate_focus::<CursorFocus>,
@@ -97,8 +155,11 @@ impl Plugin for WidgetsPlugin {
                 drag::drag_start,
                 drag::drag_end,
                 drag::dragging.after(drag::drag_start),
+                dnd::drag_and_drop,
                 scroll::scrolling_system,
                 scroll::scrolling_discrete.after(scroll::scrolling_system),
+                scroll::scroll_physics.after(scroll::scrolling_system),
+                scroll::scroll_snap.after(scroll::scroll_physics),
                 clipping::sync_camera_dimension,
             ).in_set(AouiWidgetEventSet))
             .add_systems(Update, (
@@ -110,6 +171,7 @@ impl Plugin for WidgetsPlugin {
                 button::event_conditional_visibility,
                 button::check_conditional_visibility,
                 atlas::build_deferred_atlas,
+                tooltip::tooltip_system,
             ))
             .add_systems(PostUpdate, richtext::synchronize_glyph_spaces.in_set(AouiLoadInputSet))
             .add_systems(PostUpdate, inputbox::sync_em_inputbox.in_set(AouiStoreOutputSet))
@@ -64,17 +64,20 @@
 //! Changing anchor inside is unspecified behavior.
 
 use std::{collections::HashMap, hash::{Hash, BuildHasher}, num::ParseFloatError};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
 use bevy::render::view::RenderLayers;
 use bevy::{asset::{Handle, Assets}, text::Font, render::color::Color, hierarchy::BuildChildren};
 use bevy::ecs::{entity::Entity, system::{Query, Res}, bundle::Bundle, component::Component};
 use crate::{Transform2D, Anchor, FontSize, Dimension, Size2, DimensionSize, dimension::DimensionMut, dsl::AouiCommands};
-use crate::layout::{Container, StackLayout, LayoutDir};
+use crate::layout::{Container, StackLayout, LayoutDir, LayoutObject};
 use crate::bundles::AouiBundle;
 use crate::layout::LayoutControl;
 use crate::frame;
 
 /// This widget always has the width of a space and line height of a widget.
-#[derive(Debug, Clone, Component)]
+#[derive(Debug, Clone, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct GlyphSpace {
     font: Handle<Font>
 }
@@ -606,7 +609,7 @@ impl<'a, 'w, 's, F: FontFetcher, B: Bundle + Clone> RichTextBuilder<'a, 'w, 's,
                                         ..Default::default()
                                     },
                                     Container {
-                                        layout: Box::new(StackLayout {
+                                        layout: LayoutObject::new(StackLayout {
                                             direction: LayoutDir::LeftToRight
                                         }),
                                         margin: Size2::ZERO,
@@ -1,9 +1,12 @@
 use bevy::{hierarchy::Children, math::{Vec2, IVec2}, log::warn, reflect::Reflect, ecs::{query::With, system::Res, bundle::Bundle, entity::Entity}};
-use bevy::ecs::{component::Component, query::Without};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::std_traits::ReflectDefault;
+use bevy::ecs::{component::Component, query::{Without, Has}};
 use bevy::ecs::system::{Query, Commands};
+use bevy::time::Time;
 use crate::{Transform2D, anim::Attr, anim::Offset, events::EvPositionFactor, AouiREM, DimensionData, signals::ReceiveInvoke};
 use crate::layout::{Container, LayoutControl};
-use crate::events::{EvMouseWheel, Handlers};
+use crate::events::{EvMouseWheel, EvPageChanged, Handlers};
 use crate::signals::{Invoke, KeyStorage};
 use crate::dsl::DslInto;
 
@@ -36,7 +39,10 @@ use super::constraints::{SharedPosition, PositionChanged};
 ///     For example synchronizing a scrollbar with a textbox.
 /// * [`Handlers<EvPositionFac>`]: A signal that sends a value 
 ///     in `0..=1` in its constraints when being scrolled.
+/// * [`ScrollPhysics`]: Smooth and kinetic scrolling.
+/// * [`ScrollSnap`]: Snap to children or pages when scrolling stops.
 #[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
 pub struct Scrolling {
     pub pos_x: bool,
     pub neg_x: bool,
@@ -107,13 +113,120 @@ impl ReceiveInvoke for Scrolling {
     type Type = MouseWheelAction;
 }
 
+/// Smooth and kinetic scrolling for [`Scrolling`].
+///
+/// * Wheel deltas are applied over time instead of immediately.
+/// * When continuous input like a touch pan, trackpad or a widget dragged
+///   through [`SharedPosition`] is released,
+///   scrolling continues with its velocity until slowed down by friction.
+/// * With [`ScrollConstraint`], content can be pulled past its bounds
+///   with increasing resistance, and springs back when released.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct ScrollPhysics {
+    /// Time constant in seconds for applying wheel deltas, `0` applies them immediately.
+    pub smoothing: f32,
+    /// Fraction of velocity lost per second in `0..=1`, `1` disables inertia.
+    pub friction: f32,
+    /// Inertia stops below this velocity in pixels per second.
+    pub min_velocity: f32,
+    /// Maximum distance in pixels content can be pulled past [`ScrollConstraint`] bounds,
+    /// `0` disables overscroll.
+    pub overscroll: f32,
+    /// Time constant in seconds for springing back from overscroll.
+    pub spring_back: f32,
+    pending: Vec2,
+    velocity: Vec2,
+    streak: u32,
+    input: bool,
+    driven: bool,
+    #[reflect(ignore)]
+    pub(super) bounds: Option<(Vec2, Vec2)>,
+}
+
+impl Default for ScrollPhysics {
+    fn default() -> Self {
+        Self {
+            smoothing: 0.08,
+            friction: 0.95,
+            min_velocity: 10.0,
+            overscroll: 0.0,
+            spring_back: 0.1,
+            pending: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            streak: 0,
+            input: false,
+            driven: false,
+            bounds: None,
+        }
+    }
+}
+
+impl ScrollPhysics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    pub fn with_overscroll(mut self, overscroll: f32) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    pub fn with_spring_back(mut self, spring_back: f32) -> Self {
+        self.spring_back = spring_back;
+        self
+    }
+
+    /// Velocity of inertia in pixels per second.
+    pub fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    /// Returns true if being scrolled, animating or moving by inertia.
+    pub fn is_moving(&self) -> bool {
+        self.input || self.pending != Vec2::ZERO || self.velocity != Vec2::ZERO
+    }
+
+    /// Stop smooth scrolling and inertia.
+    pub fn stop(&mut self) {
+        self.pending = Vec2::ZERO;
+        self.stop_inertia();
+    }
+
+    fn stop_inertia(&mut self) {
+        self.velocity = Vec2::ZERO;
+        self.streak = 0;
+    }
+
+    /// Track content moved by a dragged widget through [`SharedPosition`],
+    /// inertia starts when the drag is released.
+    pub(super) fn follow(&mut self, delta: Vec2, dragging: bool, dt: f32) {
+        if delta == Vec2::ZERO && !(dragging && self.streak > 0) { return; }
+        if dt > 0.0 {
+            self.velocity = self.velocity.lerp(delta / dt, 0.5);
+        }
+        self.streak += 1;
+        self.driven = true;
+    }
+}
+
 pub fn scrolling_system(
     mut commands: Commands,
     rem: Option<Res<AouiREM>>,
     storage: Res<KeyStorage>,
-    mut scroll: Query<(Entity, &Scrolling, &DimensionData, &Children, &MouseWheelAction)>,
+    mut scroll: Query<(Entity, &Scrolling, &DimensionData, &Children, &MouseWheelAction, Option<&mut ScrollPhysics>)>,
     sender: Query<(Entity, &MouseWheelAction, &Handlers<EvMouseWheel>), Without<Scrolling>>,
-    mut receiver: Query<(Entity, &Scrolling, &DimensionData, &Children, &Invoke<Scrolling>), Without<MouseWheelAction>>,
+    mut receiver: Query<(Entity, &Scrolling, &DimensionData, &Children, &Invoke<Scrolling>, Option<&mut ScrollPhysics>), Without<MouseWheelAction>>,
     mut child_query: Query<Attr<Transform2D, Offset>, With<Children>>,
 ) {
     let rem = rem.map(|x| x.get()).unwrap_or(16.0);
@@ -121,11 +234,11 @@ pub fn scrolling_system(
         signal.handle(&mut commands.entity(entity), &storage, *action);
     }
     let iter = scroll.iter_mut()
-        .map(|(entity, scroll, dim, children, action)| 
-            (entity, scroll, dim, children, *action))
-        .chain(receiver.iter_mut().filter_map(|(entity, scroll, dim, children, receiver)| 
-            Some((entity, scroll, dim, children, receiver.poll()?))));
-    for (entity, scroll, dim, children, delta) in iter {
+        .map(|(entity, scroll, dim, children, action, physics)| 
+            (entity, scroll, dim, children, *action, physics))
+        .chain(receiver.iter_mut().filter_map(|(entity, scroll, dim, children, receiver, physics)| 
+            Some((entity, scroll, dim, children, receiver.poll()?, physics))));
+    for (entity, scroll, dim, children, delta, physics) in iter {
         let delta_scroll = match (scroll.x_scroll(), scroll.y_scroll()) {
             (true, true) => delta.pixels,
             (true, false) => Vec2::new(delta.pixels.x + delta.pixels.y, 0.0),
@@ -136,6 +249,11 @@ pub fn scrolling_system(
             warn!("Component 'Scrolling' requires exactly one child as a buffer.");
             continue;
         }
+        if let Some(mut physics) = physics {
+            physics.pending += delta_scroll;
+            physics.input = true;
+            continue;
+        }
         let container = children[0];
         if let Ok(mut transform) = child_query.get_mut(container){
             transform.force_set_pixels(transform.get_pixels(dim.size, dim.em, rem) + delta_scroll);
@@ -144,11 +262,309 @@ pub fn scrolling_system(
     }
 }
 
+/// Move [`Scrolling`] content by [`ScrollPhysics`], runs after [`scrolling_system`].
+pub fn scroll_physics(
+    mut commands: Commands,
+    time: Res<Time>,
+    rem: Option<Res<AouiREM>>,
+    mut query: Query<(Entity, &mut ScrollPhysics, &DimensionData, &Children), With<Scrolling>>,
+    mut child_query: Query<Attr<Transform2D, Offset>, With<Children>>,
+) {
+    let rem = rem.map(|x| x.get()).unwrap_or(16.0);
+    let dt = time.delta_seconds();
+    if dt <= 0.0 { return; }
+    for (entity, mut physics, dim, children) in query.iter_mut() {
+        let physics = &mut *physics;
+        let input = std::mem::take(&mut physics.input);
+        let driven = std::mem::take(&mut physics.driven);
+        if children.len() != 1 { continue; }
+        let Ok(mut transform) = child_query.get_mut(children[0]) else { continue };
+        let offset = transform.get_pixels(dim.size, dim.em, rem);
+
+        let mut step = if physics.smoothing > 0.0 {
+            physics.pending * (1.0 - (-dt / physics.smoothing).exp())
+        } else {
+            physics.pending
+        };
+        physics.pending -= step;
+        if physics.pending.abs().max_element() < 0.5 {
+            step += physics.pending;
+            physics.pending = Vec2::ZERO;
+        }
+
+        if input {
+            physics.streak += 1;
+            physics.velocity = physics.velocity.lerp(step / dt, 0.5);
+        } else if driven {
+            // Moved by `scroll_constraint` this frame.
+        } else if physics.streak < 2 {
+            // A single wheel tick does not start inertia.
+            physics.stop_inertia();
+        } else {
+            step += physics.velocity * dt;
+            physics.velocity *= (1.0 - physics.friction.clamp(0.0, 1.0)).powf(dt);
+            if physics.velocity.length() < physics.min_velocity {
+                physics.stop_inertia();
+            }
+        }
+
+        if let Some((min, max)) = physics.bounds {
+            let excess = offset - offset.clamp(min, max);
+            for i in 0..2 {
+                if physics.overscroll <= 0.0 {
+                    // Inertia stops at the bounds.
+                    let next = offset[i] + step[i];
+                    if next < min[i] || next > max[i] {
+                        physics.velocity[i] = 0.0;
+                    }
+                } else if excess[i] == 0.0 {
+                    continue;
+                } else if input || physics.pending[i] != 0.0 {
+                    // Resistance increases with distance pulled.
+                    if step[i].signum() == excess[i].signum() {
+                        step[i] *= (1.0 - excess[i].abs() / physics.overscroll).max(0.0);
+                    }
+                } else {
+                    physics.velocity[i] = 0.0;
+                    step[i] = if excess[i].abs() < 0.5 || physics.spring_back <= 0.0 {
+                        -excess[i]
+                    } else {
+                        -excess[i] * (1.0 - (-dt / physics.spring_back).exp())
+                    };
+                }
+            }
+        }
+
+        if step != Vec2::ZERO {
+            transform.force_set_pixels(offset + step);
+            commands.entity(entity).insert(PositionChanged);
+        }
+    }
+}
+
+/// Snap points of [`ScrollSnap`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum SnapPoints {
+    /// Align the start edge of each child with the start edge of this sprite.
+    ChildEdge,
+    /// Align the center of each child with the center of this sprite.
+    ChildCenter,
+    /// Every `n` pixels from the start of the content.
+    Pixels(f32),
+    /// Every fraction of this sprite's size, i.e. `1.0` for full pages.
+    Percent(f32),
+}
+
+/// Snap [`Scrolling`] to snap points, or pages, when scrolling stops.
+///
+/// Snaps along the `y` axis, or the `x` axis if only `x` is scrollable.
+/// Pages are ordered from the top or the left of the content.
+///
+/// # Supporting components
+///
+/// * [`ScrollConstraint`]: Required by `Pixels` and `Percent`, limits pages to its bounds.
+/// * [`ScrollPhysics`]: Snaps after smooth scrolling and inertia come to a stop.
+/// * [`Handlers<EvPageChanged>`]: Sends the index of the page being snapped to.
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct ScrollSnap {
+    pub points: SnapPoints,
+    /// Time constant in seconds of the snapping animation, `0` snaps immediately.
+    pub smoothing: f32,
+    /// Distance in pixels scrolled away from the current page
+    /// required to move to an adjacent page.
+    pub threshold: f32,
+    page: usize,
+    page_count: usize,
+    target: Option<usize>,
+    #[reflect(ignore)]
+    pub(super) bounds: Option<(Vec2, Vec2)>,
+}
+
+impl Default for ScrollSnap {
+    fn default() -> Self {
+        Self::new(SnapPoints::ChildEdge)
+    }
+}
+
+impl ScrollSnap {
+    pub fn new(points: SnapPoints) -> Self {
+        Self {
+            points,
+            smoothing: 0.08,
+            threshold: 16.0,
+            page: 0,
+            page_count: 0,
+            target: None,
+            bounds: None,
+        }
+    }
+
+    pub fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Index of the current page.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Number of snap points found last frame.
+    pub fn page_count(&self) -> usize {
+        self.page_count
+    }
+
+    /// Animate to a page, clamped to the last page.
+    pub fn scroll_to_page(&mut self, page: usize) {
+        self.target = Some(page);
+    }
+
+    pub fn next_page(&mut self) {
+        self.scroll_to_page(self.page + 1)
+    }
+
+    pub fn prev_page(&mut self) {
+        self.scroll_to_page(self.page.saturating_sub(1))
+    }
+}
+
+/// Snap [`Scrolling`] content by [`ScrollSnap`], runs after [`scroll_physics`].
+pub fn scroll_snap(
+    mut commands: Commands,
+    time: Res<Time>,
+    storage: Res<KeyStorage>,
+    rem: Option<Res<AouiREM>>,
+    mut query: Query<(Entity, &mut ScrollSnap, &Scrolling, &DimensionData, &Children,
+        Option<&mut ScrollPhysics>,
+        Option<&Handlers<EvPageChanged>>,
+        Has<MouseWheelAction>,
+    )>,
+    mut child_query: Query<(&DimensionData, Attr<Transform2D, Offset>, Option<&Children>)>,
+) {
+    let rem = rem.map(|x| x.get()).unwrap_or(16.0);
+    let dt = time.delta_seconds();
+    for (entity, mut snap, scroll, dim, children, physics, handlers, scrolled) in query.iter_mut() {
+        if children.len() != 1 { continue; }
+        let container = children[0];
+        let size = dim.size;
+        // Offsets are ordered from the start of the content, 
+        // which is the top for `y` and the left for `x`.
+        let (axis, dir) = match (scroll.x_scroll(), scroll.y_scroll()) {
+            (true, false) => (0, -1.0),
+            (_, true) => (1, 1.0),
+            (false, false) => continue,
+        };
+        let mut points: Vec<f32> = match snap.points {
+            SnapPoints::ChildEdge | SnapPoints::ChildCenter => {
+                let Ok((container_dim, _, Some(items))) = child_query.get(container) else { continue };
+                // Children are placed relative to the container, not the viewport.
+                let container_size = container_dim.size;
+                child_query.iter_many(items).map(|(dimension, transform, _)| {
+                    let anc = container_size * transform.component.get_parent_anchor();
+                    let center = anc + transform.get_pixels(container_size, dimension.em, rem) 
+                        - dimension.size * transform.component.anchor;
+                    match snap.points {
+                        SnapPoints::ChildCenter => -center[axis],
+                        _ => dir * (size[axis] - dimension.size[axis]) / 2.0 - center[axis],
+                    }
+                }).collect()
+            }
+            SnapPoints::Pixels(n) | SnapPoints::Percent(n) => {
+                let step = match snap.points {
+                    SnapPoints::Pixels(_) => n,
+                    _ => n * size[axis],
+                };
+                let Some((min, max)) = snap.bounds else { continue };
+                if step <= 0.0 { continue; }
+                let start = if dir > 0.0 { min[axis] } else { max[axis] };
+                let len = max[axis] - min[axis];
+                let mut points: Vec<f32> = (0..=(len / step) as usize)
+                    .map(|i| start + dir * step * i as f32)
+                    .collect();
+                if len % step > 0.5 {
+                    points.push(start + dir * len);
+                }
+                points
+            }
+        };
+        if let Some((min, max)) = snap.bounds {
+            for point in points.iter_mut() {
+                *point = point.clamp(min[axis], max[axis]);
+            }
+        }
+        points.sort_by(|a, b| (a * dir).total_cmp(&(b * dir)));
+        points.dedup_by(|a, b| (*a - *b).abs() < 0.5);
+        snap.page_count = points.len();
+        if points.is_empty() { continue; }
+
+        let Ok((_, mut transform, _)) = child_query.get_mut(container) else { continue };
+        let mut offset = transform.get_pixels(size, dim.em, rem);
+        let moving = physics.as_ref().map(|x| x.is_moving()).unwrap_or(false);
+        if scrolled || (moving && snap.target.is_none()) {
+            // The user takes over.
+            snap.target = None;
+            continue;
+        }
+        if let Some(mut physics) = physics {
+            physics.stop();
+        }
+        let last = points.len() - 1;
+        let target = match snap.target {
+            Some(target) => target.min(last),
+            None => {
+                let page = snap.page.min(last);
+                let nearest = (0..points.len())
+                    .min_by(|a, b| (points[*a] - offset[axis]).abs().total_cmp(&(points[*b] - offset[axis]).abs()))
+                    .unwrap_or(page);
+                let displacement = (offset[axis] - points[page]) * dir;
+                match nearest == page && displacement.abs() > snap.threshold {
+                    true if displacement > 0.0 => (page + 1).min(last),
+                    true => page.saturating_sub(1),
+                    false => nearest,
+                }
+            }
+        };
+        let mut commands = commands.entity(entity);
+        if snap.page != target {
+            snap.page = target;
+            if let Some(handlers) = handlers {
+                handlers.handle(&mut commands, &storage, target);
+            }
+        }
+        let goal = points[target];
+        if offset[axis] == goal {
+            snap.target = None;
+            continue;
+        }
+        snap.target = Some(target);
+        let step = if snap.smoothing > 0.0 {
+            (goal - offset[axis]) * (1.0 - (-dt / snap.smoothing).exp())
+        } else {
+            goal - offset[axis]
+        };
+        if (goal - offset[axis] - step).abs() < 0.5 {
+            offset[axis] = goal;
+            snap.target = None;
+        } else {
+            offset[axis] += step;
+        }
+        transform.force_set_pixels(offset);
+        commands.insert(PositionChanged);
+    }
+}
+
 /// Marker component for making scrolling affect 
 /// the `range` value on a layout.
 /// 
 /// This implementation has the benefit of not requiring clipping.
 #[derive(Debug, Clone, Copy, Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub enum ScrollDiscrete {
     XPos,
     XNeg,
@@ -0,0 +1,285 @@
+use bevy::ecs::{entity::Entity, component::Component};
+use bevy::ecs::system::{Query, Res};
+use bevy::ecs::query::{With, Without};
+use bevy::hierarchy::{DespawnRecursiveExt, Parent};
+use bevy::math::{Vec2, Rect};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::reflect::{Reflect, std_traits::ReflectDefault};
+use bevy::render::camera::Camera;
+use bevy::render::view::Visibility;
+use bevy::time::Time;
+use bevy::transform::components::GlobalTransform;
+use bevy::window::{Window, PrimaryWindow};
+
+use crate::{Anchor, Opacity, RotatedRect, Transform2D};
+use crate::anim::{Interpolate, Easing};
+use crate::dsl::{AouiCommands, Widget, builders::TextBuilder};
+use crate::events::{AouiCamera, CursorFocus, CursorState, EventFlags};
+
+use super::clipping::CameraClip;
+
+/// Content of a [`Tooltip`], spawned as an unparented sprite when first displayed.
+pub enum TooltipContent {
+    /// Spawn a `text` with default settings.
+    Text(String),
+    /// Spawn a widget, the returned entity must be unparented.
+    Widget(Box<dyn FnOnce(&mut AouiCommands) -> Entity + Send + Sync>),
+    /// Use an existing unparented sprite, hidden until displayed.
+    Entity(Entity),
+}
+
+impl std::fmt::Debug for TooltipContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Widget(_) => f.debug_tuple("Widget").finish(),
+            Self::Entity(entity) => f.debug_tuple("Entity").field(entity).finish(),
+        }
+    }
+}
+
+/// Placement of a [`Tooltip`] relative to its target.
+///
+/// The tooltip flips to the opposite side if it does not fit inside the window,
+/// then shifts to stay inside the window if possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum TooltipPlacement {
+    #[default]
+    Top,
+    Bottom,
+    Left,
+    Right,
+    /// Below and to the right of the cursor.
+    Cursor,
+}
+
+/// Display a sprite after hovering on this widget for some time,
+/// hide it when the cursor leaves.
+///
+/// # Supporting components
+///
+/// * [`EventFlags`]: Requires `Hover` to be set.
+/// * [`Interpolate<Opacity>`]: Inserted on the tooltip if missing, used for fading in and out.
+///   The tooltip is set to [`Visibility::Hidden`] once faded out.
+#[derive(Debug, Component)]
+pub struct Tooltip {
+    pub content: TooltipContent,
+    /// Time in seconds the cursor must hover before displaying the tooltip.
+    pub delay: f32,
+    pub placement: TooltipPlacement,
+    /// Distance in pixels between the tooltip and the target or cursor.
+    pub gap: f32,
+    /// Time in seconds of the fade in and fade out animation.
+    pub fade: f32,
+    hover_time: f32,
+    shown: bool,
+}
+
+impl Tooltip {
+    pub fn new(content: TooltipContent) -> Self {
+        Self {
+            content,
+            delay: 0.5,
+            placement: TooltipPlacement::Top,
+            gap: 4.0,
+            fade: 0.15,
+            hover_time: 0.0,
+            shown: false,
+        }
+    }
+
+    /// Create a tooltip that displays some text.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(TooltipContent::Text(text.into()))
+    }
+
+    /// Create a tooltip that spawns a widget.
+    pub fn widget(widget: impl Widget + Send + Sync + 'static) -> Self {
+        Self::new(TooltipContent::Widget(Box::new(move |commands| widget.spawn(commands).0)))
+    }
+
+    /// Create a tooltip that displays an existing unparented sprite.
+    pub fn entity(entity: Entity) -> Self {
+        Self::new(TooltipContent::Entity(entity))
+    }
+
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn with_gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    pub fn with_fade(mut self, fade: f32) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Returns true if the tooltip is being displayed.
+    pub fn is_shown(&self) -> bool {
+        self.shown
+    }
+}
+
+/// Marks the sprite displayed by a [`Tooltip`].
+#[derive(Debug, Clone, Copy, Component, Reflect)]
+#[reflect(Component, Default)]
+pub struct TooltipOf {
+    target: Entity,
+    owned: bool,
+}
+
+impl Default for TooltipOf {
+    fn default() -> Self {
+        Self { target: Entity::PLACEHOLDER, owned: false }
+    }
+}
+
+impl TooltipOf {
+    /// The widget this tooltip belongs to.
+    pub fn target(&self) -> Entity {
+        self.target
+    }
+}
+
+/// Find the tooltip's anchor point and anchor, flipped and shifted to stay inside `window`.
+fn place(placement: TooltipPlacement, target: Rect, cursor: Vec2, gap: f32, size: Vec2, window: Option<Rect>) -> (Vec2, Anchor) {
+    let (pivot, mut position, mut anchor) = match placement {
+        TooltipPlacement::Top => (target.center(), Vec2::new(target.center().x, target.max.y + gap), Anchor::BottomCenter.as_vec()),
+        TooltipPlacement::Bottom => (target.center(), Vec2::new(target.center().x, target.min.y - gap), Anchor::TopCenter.as_vec()),
+        TooltipPlacement::Left => (target.center(), Vec2::new(target.min.x - gap, target.center().y), Anchor::CenterRight.as_vec()),
+        TooltipPlacement::Right => (target.center(), Vec2::new(target.max.x + gap, target.center().y), Anchor::CenterLeft.as_vec()),
+        TooltipPlacement::Cursor => (cursor, cursor + Vec2::new(gap, -gap), Anchor::TopLeft.as_vec()),
+    };
+    let Some(window) = window else {
+        return (position, Anchor::new(anchor));
+    };
+    let bounds = |position: Vec2, anchor: Vec2| {
+        let min = position - (anchor + 0.5) * size;
+        Rect { min, max: min + size }
+    };
+    let fits_x = |rect: Rect| rect.min.x >= window.min.x && rect.max.x <= window.max.x;
+    let fits_y = |rect: Rect| rect.min.y >= window.min.y && rect.max.y <= window.max.y;
+    // Mirror around the target or cursor if the flipped side fits.
+    if anchor.x != 0.0 && !fits_x(bounds(position, anchor)) {
+        let flipped = (Vec2::new(pivot.x * 2.0 - position.x, position.y), anchor * Vec2::new(-1.0, 1.0));
+        if fits_x(bounds(flipped.0, flipped.1)) {
+            (position, anchor) = flipped;
+        }
+    }
+    if anchor.y != 0.0 && !fits_y(bounds(position, anchor)) {
+        let flipped = (Vec2::new(position.x, pivot.y * 2.0 - position.y), anchor * Vec2::new(1.0, -1.0));
+        if fits_y(bounds(flipped.0, flipped.1)) {
+            (position, anchor) = flipped;
+        }
+    }
+    let rect = bounds(position, anchor);
+    let shift = (window.min - rect.min).max(Vec2::ZERO) + (window.max - rect.max).min(Vec2::ZERO);
+    (position + shift, Anchor::new(anchor))
+}
+
+/// Display [`Tooltip`]s of hovered widgets.
+pub fn tooltip_system(
+    mut commands: AouiCommands,
+    time: Res<Time>,
+    state: Res<CursorState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    marked_camera: Query<(&Camera, &GlobalTransform), With<AouiCamera>>,
+    unmarked_camera: Query<(&Camera, &GlobalTransform), (Without<AouiCamera>, Without<CameraClip>)>,
+    mut targets: Query<(Entity, &mut Tooltip, &RotatedRect, Option<&CursorFocus>)>,
+    mut tips: Query<(Entity, &TooltipOf, &mut Transform2D, &RotatedRect, &mut Visibility, Option<&mut Interpolate<Opacity>>, Option<&Parent>), Without<Tooltip>>,
+    parents: Query<&RotatedRect>,
+) {
+    let camera = match marked_camera.get_single() {
+        Ok(camera) => Some(camera),
+        Err(_) => unmarked_camera.get_single().ok(),
+    };
+    let window = windows.get_single().ok().zip(camera).and_then(|(window, (camera, camera_transform))| {
+        let a = camera.viewport_to_world_2d(camera_transform, Vec2::ZERO)?;
+        let b = camera.viewport_to_world_2d(camera_transform, Vec2::new(window.width(), window.height()))?;
+        Some(Rect::from_corners(a, b))
+    });
+
+    for (entity, tip, ..) in tips.iter() {
+        if !targets.contains(tip.target) {
+            if tip.owned {
+                commands.entity(entity).despawn_recursive();
+            } else {
+                commands.entity(entity).remove::<TooltipOf>();
+            }
+        }
+    }
+
+    for (entity, mut tooltip, rect, focus) in targets.iter_mut() {
+        let hovering = focus.map(|x| x.is(EventFlags::Hover)).unwrap_or(false);
+        tooltip.hover_time = if hovering { tooltip.hover_time + time.delta_seconds() } else { 0.0 };
+        let show = hovering && tooltip.hover_time >= tooltip.delay;
+
+        let tip = match &tooltip.content {
+            TooltipContent::Entity(tip) => *tip,
+            _ if !show => continue,
+            _ => {
+                let (tip, owned) = match std::mem::replace(&mut tooltip.content, TooltipContent::Entity(Entity::PLACEHOLDER)) {
+                    TooltipContent::Text(text) => (TextBuilder { text, ..Default::default() }.spawn(&mut commands).0, true),
+                    TooltipContent::Widget(f) => (f(&mut commands), true),
+                    TooltipContent::Entity(tip) => (tip, false),
+                };
+                tooltip.content = TooltipContent::Entity(tip);
+                commands.entity(tip).insert((
+                    TooltipOf { target: entity, owned },
+                    Opacity::TRANSPARENT,
+                    Interpolate::<Opacity>::new(Easing::Linear, 0.0, tooltip.fade),
+                ));
+                continue;
+            }
+        };
+        let Ok((_, _, mut transform, tip_rect, mut visibility, interpolate, parent)) = tips.get_mut(tip) else {
+            // Hide a newly assigned existing sprite.
+            if let Some(mut tip) = commands.commands().get_entity(tip) {
+                tip.insert((
+                    TooltipOf { target: entity, owned: false },
+                    Opacity::TRANSPARENT,
+                    Interpolate::<Opacity>::new(Easing::Linear, 0.0, tooltip.fade),
+                ));
+            }
+            continue;
+        };
+        let Some(mut interpolate) = interpolate else {
+            commands.entity(tip).insert(Interpolate::<Opacity>::new(Easing::Linear, 0.0, tooltip.fade));
+            continue;
+        };
+        if show {
+            let (position, anchor) = place(
+                tooltip.placement,
+                rect.rect(),
+                state.cursor_position(),
+                tooltip.gap,
+                tip_rect.rect().size(),
+                window,
+            );
+            // `position` is in world space, convert it to an offset from the parent's center.
+            let offset = match parent.and_then(|x| parents.get(x.get()).ok()) {
+                Some(parent) => parent.local_space(position) / parent.scale,
+                None => position,
+            };
+            transform.anchor = anchor;
+            transform.parent_anchor = Anchor::Center;
+            transform.offset = offset.into();
+            *visibility = Visibility::Inherited;
+        }
+        if show != tooltip.shown {
+            tooltip.shown = show;
+            interpolate.interpolate_to(if show { 1.0 } else { 0.0 });
+        } else if !show && interpolate.get() <= 0.0 && *visibility != Visibility::Hidden {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
@@ -26,8 +26,8 @@
 
 
 use aoui::anim::Interpolation;
-use bevy::{app::{Plugin, PostUpdate, Update}, asset::load_internal_asset, render::render_resource::Shader, ecs::schedule::IntoSystemConfigs, sprite::Material2dPlugin};
-use bevy_aoui::schedule::AouiStoreOutputSet;
+use bevy::{app::{Plugin, PostUpdate, Update}, asset::load_internal_asset, render::render_resource::Shader, ecs::schedule::IntoSystemConfigs};
+use bevy_aoui::{schedule::AouiStoreOutputSet, AouiMaterialPlugin, widgets::clipping::ClippingMaterialPlugin};
 
 use crate::{shapes::*, widgets::{btn_color_change, toggle_color_change, toggle_dial_change, button::btn_stroke_change}};
 
@@ -46,19 +46,18 @@ pub struct MatuiPlugin;
 
 impl Plugin for MatuiPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_plugins(Material2dPlugin::<RoundedRectangleMaterial>::default());
-        app.add_plugins(Material2dPlugin::<RoundedShadowMaterial>::default());
+        app.add_plugins(AouiMaterialPlugin::<RoundedRectangleMaterial>::default());
+        app.add_plugins(AouiMaterialPlugin::<RoundedShadowMaterial>::default());
+        app.add_plugins(ClippingMaterialPlugin::<RoundedRectangleMaterial>::default());
+        app.add_plugins(ClippingMaterialPlugin::<RoundedShadowMaterial>::default());
         load_internal_asset!(app, ROUNDED_RECTANGLE_SHADER, "shaders/rounded_rectangle.wgsl", Shader::from_wgsl);
         load_internal_asset!(app, ROUNDED_SHADOW_SHADER, "shaders/rounded_shadow.wgsl", Shader::from_wgsl);
         app.add_systems(PostUpdate, (
             sync_rounded_rect,
             sync_rounded_shadow,
-            sync_rounded_rect_opacity,
-            sync_rounded_shadow_opacity,
         ).in_set(AouiStoreOutputSet));
         app.add_systems(Update, (
             interpolate_stroke_color,
-            interpolate_round_rect_color,
             StrokeColor::update_interpolate,
         ));
         app.add_systems(Update, (
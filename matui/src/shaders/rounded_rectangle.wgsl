@@ -1,4 +1,5 @@
 #import bevy_sprite::mesh2d_vertex_output::VertexOutput,
+#import bevy_aoui::clip::aoui_clip
 
 @group(1) @binding(0)
 var<uniform> color: vec4<f32>;
@@ -25,6 +26,9 @@ var texture: texture_2d<f32>;
 @group(1) @binding(7) 
 var samplr: sampler;
 
+@group(1) @binding(8)
+var<uniform> clip: vec4<f32>;
+
 fn sdf(in: vec2<f32>) -> f32 {
     if (in.x > 0.0 && in.y > 0.0) {
         return sqrt(in.x * in.x + in.y * in.y);
@@ -66,5 +70,5 @@ fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
 
     let factor = 1.0 - smoothstep(radius - 2.0, radius, length);
     let fill = color * factor;
-    return fill * (1.0 - stroke_fac) + stroke_color * stroke_fac;
+    return (fill * (1.0 - stroke_fac) + stroke_color * stroke_fac) * aoui_clip(in.world_position.xy, clip);
 }
\ No newline at end of file
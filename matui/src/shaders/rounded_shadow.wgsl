@@ -1,4 +1,5 @@
 #import bevy_sprite::mesh2d_vertex_output::VertexOutput,
+#import bevy_aoui::clip::aoui_clip
 
 @group(1) @binding(0)
 var<uniform> color: vec4<f32>;
@@ -16,6 +17,9 @@ var<uniform> capsule: f32;
 @group(1) @binding(4)
 var<uniform> corners: vec4<f32>;
 
+@group(1) @binding(5)
+var<uniform> clip: vec4<f32>;
+
 fn sdf(in: vec2<f32>) -> f32 {
     if (in.x > 0.0 && in.y > 0.0) {
         return sqrt(in.x * in.x + in.y * in.y);
@@ -49,5 +53,5 @@ fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
 
     var factor = sdf(position);
     factor = smoothstep(0.5, 0.75, sigmoid(1.0 - smoothstep(radius - shadow_size, radius + shadow_size, factor)));
-    return  color * factor;
+    return  color * factor * aoui_clip(in.world_position.xy, clip);
 }
\ No newline at end of file
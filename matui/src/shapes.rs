@@ -4,7 +4,8 @@ use bevy::asset::{Asset, Handle, Assets};
 use bevy::math::{Vec2, Vec4};
 use bevy::render::{color::Color, texture::Image};
 use bevy::render::render_resource::{AsBindGroup, ShaderRef, Shader};
-use bevy_aoui::{anim::{Interpolate, Interpolation}, dsl::DslInto, DimensionData, Opacity};
+use bevy_aoui::{anim::{Interpolate, Interpolation}, dsl::DslInto, DimensionData, AouiMaterial};
+use bevy_aoui::widgets::clipping::{ClippingMaterial, UNCLIPPED};
 
 use crate::builders::Stroke;
 
@@ -12,7 +13,7 @@ pub const ROUNDED_RECTANGLE_SHADER: Handle<Shader> =       Handle::weak_from_u12
 pub const ROUNDED_SHADOW_SHADER: Handle<Shader> =          Handle::weak_from_u128(270839355282343875567970925758141260071);
 
 /// If you 
-#[derive(AsBindGroup, Asset, TypePath, Debug, Clone, Default)]
+#[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
 #[non_exhaustive]
 pub struct RoundedShadowMaterial {
     /// The background color of the material
@@ -27,7 +28,24 @@ pub struct RoundedShadowMaterial {
     pub capsule: f32,
     #[uniform(4)]
     pub corners: Vec4,
+    /// Clip rect, written from `Clipping`.
+    #[uniform(5)]
+    pub clip: Vec4,
 }
+
+impl Default for RoundedShadowMaterial {
+    fn default() -> Self {
+        Self { 
+            color: Color::default(), 
+            shadow_size: 0.0, 
+            size: Vec2::ZERO, 
+            capsule: 0.0, 
+            corners: Vec4::ZERO, 
+            clip: UNCLIPPED,
+        }
+    }
+}
+
 impl RoundedShadowMaterial {
     pub fn new(color: Color, corner: f32, size: f32) -> Self {
         Self { 
@@ -36,6 +54,7 @@ impl RoundedShadowMaterial {
             size: Vec2::ZERO,
             capsule: 0.0,
             corners: Vec4::splat(corner),
+            clip: UNCLIPPED,
         }
     }
 
@@ -46,6 +65,7 @@ impl RoundedShadowMaterial {
             size: Vec2::ZERO,
             capsule: 1.0,
             corners: Vec4::ZERO,
+            clip: UNCLIPPED,
         }
     }
 }
@@ -56,8 +76,28 @@ impl Material2d for RoundedShadowMaterial {
     }
 }
 
+impl AouiMaterial for RoundedShadowMaterial {
+    fn set_opacity(&mut self, opacity: f32) {
+        self.color.set_a(opacity);
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.color = color
+    }
+}
+
+impl ClippingMaterial for RoundedShadowMaterial {
+    fn clip_rect(&self) -> Vec4 {
+        self.clip
+    }
 
-#[derive(AsBindGroup, Asset, TypePath, Debug, Clone, Default)]
+    fn set_clip_rect(&mut self, rect: Vec4) {
+        self.clip = rect
+    }
+}
+
+
+#[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
 #[non_exhaustive]
 pub struct RoundedRectangleMaterial {
     /// The background color of the material
@@ -78,7 +118,16 @@ pub struct RoundedRectangleMaterial {
     pub corners: Vec4,
     #[texture(6)]
     #[sampler(7)]
-    pub image: Option<Handle<Image>>
+    pub image: Option<Handle<Image>>,
+    /// Clip rect, written from `Clipping`.
+    #[uniform(8)]
+    pub clip: Vec4,
+}
+
+impl Default for RoundedRectangleMaterial {
+    fn default() -> Self {
+        Self::rect(Color::default())
+    }
 }
 
 pub trait IntoCorners {
@@ -109,7 +158,8 @@ impl RoundedRectangleMaterial {
         Self { 
             color, image: None, corners: corner.into_corners(), size: Vec2::ZERO,
             capsule: 0.0,
-            stroke_color: Color::NONE, stroke_size: 0.0 
+            stroke_color: Color::NONE, stroke_size: 0.0,
+            clip: UNCLIPPED,
         }
     }
 
@@ -118,7 +168,8 @@ impl RoundedRectangleMaterial {
         Self { 
             color, image: None, corners: Vec4::ZERO, size: Vec2::ZERO,
             capsule: 1.0,
-            stroke_color: Color::NONE, stroke_size: 0.0 
+            stroke_color: Color::NONE, stroke_size: 0.0,
+            clip: UNCLIPPED,
         }
     }
 
@@ -126,7 +177,8 @@ impl RoundedRectangleMaterial {
         Self { 
             color, image: None, corners: Vec4::ZERO, size: Vec2::ZERO,
             capsule: 0.0,
-            stroke_color: Color::NONE, stroke_size: 0.0 
+            stroke_color: Color::NONE, stroke_size: 0.0,
+            clip: UNCLIPPED,
         }
     }
     
@@ -135,7 +187,8 @@ impl RoundedRectangleMaterial {
         Self { 
             color, image: Some(image), corners: corner.into_corners(), size: Vec2::ZERO,
             capsule: 0.0,
-            stroke_color: Color::NONE, stroke_size: 0.0 
+            stroke_color: Color::NONE, stroke_size: 0.0,
+            clip: UNCLIPPED,
         }
     }
 
@@ -143,7 +196,8 @@ impl RoundedRectangleMaterial {
         Self { 
             color, image: Some(image), corners: Vec4::ZERO, size: Vec2::ZERO,
             capsule: 1.0,
-            stroke_color: Color::NONE, stroke_size: 0.0 
+            stroke_color: Color::NONE, stroke_size: 0.0,
+            clip: UNCLIPPED,
         }
     }
 
@@ -161,6 +215,26 @@ impl Material2d for RoundedRectangleMaterial {
     }
 }
 
+impl AouiMaterial for RoundedRectangleMaterial {
+    fn set_opacity(&mut self, opacity: f32) {
+        self.color.set_a(opacity);
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.color = color
+    }
+}
+
+impl ClippingMaterial for RoundedRectangleMaterial {
+    fn clip_rect(&self) -> Vec4 {
+        self.clip
+    }
+
+    fn set_clip_rect(&mut self, rect: Vec4) {
+        self.clip = rect
+    }
+}
+
 pub fn sync_rounded_rect(
     query: Query<(&Handle<RoundedRectangleMaterial>, &DimensionData)>,
     mut assets: ResMut<Assets<RoundedRectangleMaterial>>
@@ -171,17 +245,6 @@ pub fn sync_rounded_rect(
     }
 }
 
-pub fn sync_rounded_rect_opacity(
-    query: Query<(&Handle<RoundedRectangleMaterial>, &Opacity)>,
-    mut assets: ResMut<Assets<RoundedRectangleMaterial>>
-){
-    for (handle, opacity) in query.iter() {
-        let Some(asset) = assets.get_mut(handle) else {return};
-        asset.color.set_a(opacity.get());
-    }
-}
-
-
 pub fn sync_rounded_shadow(
     query: Query<(&Handle<RoundedShadowMaterial>, &DimensionData)>, 
     mut assets: ResMut<Assets<RoundedShadowMaterial>>
@@ -192,26 +255,6 @@ pub fn sync_rounded_shadow(
     }
 }
 
-pub fn sync_rounded_shadow_opacity(
-    query: Query<(&Handle<RoundedShadowMaterial>, &Opacity)>,
-    mut assets: ResMut<Assets<RoundedShadowMaterial>>
-){
-    for (handle, opacity) in query.iter() {
-        let Some(asset) = assets.get_mut(handle) else {return};
-        asset.color.set_a(opacity.get());
-    }
-}
-
-pub fn interpolate_round_rect_color(
-    query: Query<(&Interpolate<Color>, &Handle<RoundedRectangleMaterial>)>, 
-    mut assets: ResMut<Assets<RoundedRectangleMaterial>> 
-){
-    for (interpolate, material) in query.iter() {
-        let Some(asset) = assets.get_mut(material) else {return};
-        asset.color = interpolate.get()
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
 pub enum StrokeColor {}
 